@@ -1,80 +1,794 @@
-use crate::lokalise_client::Project;
+use crate::config::{Config, MessageFormat, NameCollisions, SplitConfig, Target, ValidationConfig};
+use crate::icu;
+use crate::kotlin;
+use crate::lokalise_client::{Platform, Project};
+use crate::names::{self, Collision};
+use crate::plural_rules::{self, PluralCategory};
+use crate::swift;
+use crate::typescript;
+use crate::validation;
 use crate::{lokalise_client::Key, scala_ast::*};
 use anyhow::{Error, Result};
-use heck::{CamelCase, MixedCase, TitleCase};
+use heck::{CamelCase, MixedCase};
 use regex::Regex;
 use serde::Deserialize;
-use std::{collections::HashSet, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    iter,
+    path::PathBuf,
+    str::FromStr,
+};
 
-pub fn generate_code(projects: Vec<(Project, Vec<Key>)>) -> Result<String> {
-    let mut items = Vec::new();
+/// The code generated for a target.
+pub enum Output {
+    /// A single file, written to the target's `output`.
+    File(String),
+    /// Files and their paths, relative to the directory containing the config file.
+    Files(Vec<(PathBuf, String)>),
+}
+
+pub fn generate_code(
+    projects: Vec<(Project, Vec<Key>)>,
+    config: &Config,
+    target: Target,
+) -> Result<Output> {
+    let translations = Translations::new(
+        projects,
+        target,
+        config.platform(target),
+        config.fallback_locale.as_deref(),
+        config.message_format,
+        &config.validation,
+    )?;
+    validation::check_placeholders(&translations, &config.validation)?;
 
-    items.push(Item::Comment(Comment::new("format: off")));
+    match target {
+        Target::Scala => match &config.split {
+            Some(split) => scala_files(&translations, config, split).map(Output::Files),
+            None => scala_code(&translations, config).map(Output::File),
+        },
+        Target::TypeScript => typescript::generate_code(&translations).map(Output::File),
+        Target::Kotlin => kotlin::generate_code(&translations, config).map(Output::File),
+        Target::Swift => swift::generate_code(&translations, config).map(Output::File),
+    }
+}
 
-    items.extend(hardcoded_items());
+fn scala_code(translations: &Translations, config: &Config) -> Result<String> {
+    let mut items = shared_items(translations)?;
 
-    let all_keys = projects
+    let items_inside_i18n_obj = translations
+        .projects
         .iter()
-        .flat_map(|(_, keys)| keys)
+        .map(project_object)
         .collect::<Vec<_>>();
 
-    items.extend(vec![
-        Item::Trait {
-            name: "Locale".to_string(),
-            sealed: true,
-        },
-        Item::Object {
-            case: false,
-            name: "Locale".to_string(),
-            items: locale_enum_variants(&all_keys),
-            methods: vec![],
-            super_type: None,
-        },
-    ]);
-
-    let items_inside_i18n_obj = projects
-        .into_iter()
-        .map(|(project, keys)| {
-            let methods = translation_methods(&keys)?;
-            Ok(Item::Object {
-                case: false,
-                name: project.name.to_mixed_case(),
-                items: vec![],
-                methods,
-                super_type: None,
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
-
     items.extend(vec![Item::Object {
         case: false,
-        name: "I18n".to_string(),
+        name: Ident::new(config.object()),
         items: items_inside_i18n_obj,
         methods: vec![],
         super_type: None,
     }]);
 
-    items.push(Item::Comment(Comment::new("format: on")));
+    Ok(scala_file(items, config))
+}
+
+/// A file per project containing its object as a top-level object of the package, and a file
+/// with the types they share.
+fn scala_files(
+    translations: &Translations,
+    config: &Config,
+    split: &SplitConfig,
+) -> Result<Vec<(PathBuf, String)>> {
+    let shared_path = split.dir.join(&split.shared_file);
+    let mut owners = HashMap::new();
+    owners.insert(shared_path.clone(), "the shared types".to_string());
+
+    let mut files = vec![(
+        shared_path,
+        with_header(scala_file(shared_items(translations)?, config)),
+    )];
+
+    for project in &translations.projects {
+        let file_name = split
+            .project_file
+            .replace("{project}", &project.name.to_mixed_case());
+        let path = split.dir.join(file_name);
+
+        let owner = format!("the {:?} project", project.name);
+        if let Some(other) = owners.insert(path.clone(), owner.clone()) {
+            return Err(Error::msg(format!(
+                "Both {} and {} would be written to {}. Use `{{project}}` in `project_file` \
+                 in the `[split]` config section to give each project its own file.",
+                other,
+                owner,
+                path.display()
+            )));
+        }
+
+        files.push((
+            path,
+            with_header(scala_file(vec![project_object(project)], config)),
+        ));
+    }
+
+    Ok(files)
+}
+
+/// `Cardinality`, `Locale`, the select types and, when needed, the formatting helpers.
+fn shared_items(translations: &Translations) -> Result<Vec<Item>> {
+    let mut items = Vec::new();
+
+    items.push(cardinality_item(&translations.locales)?);
+
+    items.push(Item::Enum {
+        name: Ident::new("Locale"),
+        cases: translations
+            .locales
+            .iter()
+            .map(|locale| Ident::new(locale.to_camel_case()))
+            .collect(),
+        case_objects: false,
+        methods: vec![],
+    });
+
+    for select in &translations.selects {
+        items.push(select_item(select));
+    }
+
+    if translations.uses_formatted_placeholders() {
+        items.extend(format_items(&translations.locales));
+    }
+
+    Ok(items)
+}
+
+/// An object with a method per key of the project.
+fn project_object(project: &ProjectTranslations) -> Item {
+    Item::Object {
+        case: false,
+        name: Ident::new(project.name.to_mixed_case()),
+        items: vec![],
+        methods: translation_methods(&project.keys),
+        super_type: None,
+    }
+}
+
+/// The first line of every file written with `[split]`, so generated files can be told apart from
+/// the files around them.
+pub const GENERATED_HEADER: &str = "// Generated by i18n-code-gen. Don't edit.";
+
+/// The code of a file declaring the package and containing `items`.
+fn scala_file(items: Vec<Item>, config: &Config) -> String {
+    let mut all_items = Vec::new();
+
+    all_items.push(Item::Comment(Comment::new("format: off")));
+    all_items.extend(hardcoded_items(config));
+    all_items.extend(items);
+    all_items.push(Item::Comment(Comment::new("format: on")));
+
+    to_code(TopLevel { items: all_items }, config.dialect)
+}
+
+/// `code` of a split file, starting with `GENERATED_HEADER`.
+fn with_header(code: String) -> String {
+    format!("{}\n{}", GENERATED_HEADER, code)
+}
+
+/// Everything the code generators need to know about the translations, independent of the
+/// language being generated.
+#[derive(Debug)]
+pub struct Translations {
+    pub locales: Vec<String>,
+    pub selects: Vec<SelectType>,
+    pub projects: Vec<ProjectTranslations>,
+}
+
+/// A type generated for the values of the arguments selects with the same name match on.
+#[derive(Debug)]
+pub struct SelectType {
+    /// The name of the type, which is the name of the arguments in `CamelCase`.
+    pub name: String,
+    /// The values matched on by any translation in the order they're first seen, followed by
+    /// `other`.
+    pub cases: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct ProjectTranslations {
+    pub name: String,
+    pub keys: Vec<KeyTranslations>,
+}
+
+#[derive(Debug)]
+pub struct KeyTranslations {
+    pub name: String,
+    /// The name of the generated function.
+    pub method_name: String,
+    pub plural: bool,
+    pub placeholders: Vec<Placeholder>,
+    pub values: Vec<LocalizedMessage>,
+}
+
+#[derive(Debug)]
+pub struct LocalizedMessage {
+    pub locale: String,
+    pub message: Message,
+    /// Whether the key isn't translated to the locale and `message` was filled in for it.
+    pub filled_in: bool,
+}
+
+/// A translation, as a tree of matches on arguments with the text to use at the leaves.
+#[derive(Debug, Clone)]
+pub enum Message {
+    Text(Vec<Segment>),
+    /// Picks a form by the plural category of the integer `argument`, unless it's one of the
+    /// `exact` values.
+    Plural {
+        argument: String,
+        exact: Vec<(u32, Message)>,
+        forms: Vec<(PluralCategory, Message)>,
+        other: Box<Message>,
+    },
+    /// Picks a case by the value of `argument`, whose type is the `SelectType` of its name.
+    Select {
+        argument: String,
+        cases: Vec<(String, Message)>,
+        other: Box<Message>,
+    },
+}
+
+impl Message {
+    /// The placeholders in every branch of the message.
+    pub fn placeholders(&self) -> Vec<&Placeholder> {
+        match self {
+            Message::Text(segments) => segments
+                .iter()
+                .filter_map(|segment| match segment {
+                    Segment::Text(_) => None,
+                    Segment::Placeholder(placeholder) => Some(placeholder),
+                })
+                .collect(),
+            _ => self
+                .branches()
+                .into_iter()
+                .flat_map(Message::placeholders)
+                .collect(),
+        }
+    }
+
+    /// The names of the placeholders and the arguments matched on.
+    fn argument_names(&self) -> Vec<&str> {
+        self.placeholders()
+            .into_iter()
+            .map(|placeholder| placeholder.name.as_str())
+            .chain(match self {
+                Message::Text(_) => None,
+                Message::Plural { argument, .. } | Message::Select { argument, .. } => {
+                    Some(argument.as_str())
+                }
+            })
+            .chain(
+                self.branches()
+                    .into_iter()
+                    .flat_map(Message::argument_names),
+            )
+            .collect()
+    }
+
+    /// Rename the placeholders and the arguments matched on.
+    fn rename_arguments(&mut self, renames: &HashMap<String, String>) {
+        let rename = |name: &mut String| {
+            if let Some(new_name) = renames.get(name) {
+                *name = new_name.clone();
+            }
+        };
+
+        match self {
+            Message::Text(segments) => {
+                for segment in segments {
+                    if let Segment::Placeholder(placeholder) = segment {
+                        rename(&mut placeholder.name);
+                    }
+                }
+            }
+            Message::Plural {
+                argument,
+                exact,
+                forms,
+                other,
+            } => {
+                rename(argument);
+                for (_, message) in exact {
+                    message.rename_arguments(renames);
+                }
+                for (_, message) in forms {
+                    message.rename_arguments(renames);
+                }
+                other.rename_arguments(renames);
+            }
+            Message::Select {
+                argument,
+                cases,
+                other,
+            } => {
+                rename(argument);
+                for (_, message) in cases {
+                    message.rename_arguments(renames);
+                }
+                other.rename_arguments(renames);
+            }
+        }
+    }
+
+    /// The arguments matched on, outermost first.
+    pub fn selectors(&self) -> Vec<Argument> {
+        let (name, kind) = match self {
+            Message::Text(_) => return Vec::new(),
+            Message::Plural { argument, .. } => (argument, PlaceholderKind::Integer),
+            Message::Select { argument, .. } => (argument, PlaceholderKind::Select),
+        };
+
+        let mut selectors = vec![Argument {
+            name: name.clone(),
+            kind,
+        }];
+        selectors.extend(self.branches().into_iter().flat_map(Message::selectors));
+        selectors
+    }
+
+    /// The messages matched between, with `other` last.
+    fn branches(&self) -> Vec<&Message> {
+        match self {
+            Message::Text(_) => Vec::new(),
+            Message::Plural {
+                exact,
+                forms,
+                other,
+                ..
+            } => exact
+                .iter()
+                .map(|(_, message)| message)
+                .chain(forms.iter().map(|(_, message)| message))
+                .chain(iter::once(&**other))
+                .collect(),
+            Message::Select { cases, other, .. } => cases
+                .iter()
+                .map(|(_, message)| message)
+                .chain(iter::once(&**other))
+                .collect(),
+        }
+    }
+}
+
+/// A piece of a translated text.
+#[derive(Debug, Clone)]
+pub enum Segment {
+    Text(String),
+    Placeholder(Placeholder),
+}
+
+/// A parameter of a generated translation function.
+#[derive(Debug)]
+pub struct Argument {
+    pub name: String,
+    pub kind: PlaceholderKind,
+}
+
+impl Translations {
+    /// Analyse the keys of each project.
+    ///
+    /// Keys are named after their name on `platform`, converted to identifiers of `target`, and
+    /// translations parsed as
+    /// `message_format`. Keys missing a translation for a locale get the `fallback_locale` text
+    /// if it's given, or else an empty text with a warning, which is an error if `validation`
+    /// is strict. Names that become the same identifier are handled according to `validation`.
+    fn new(
+        projects: Vec<(Project, Vec<Key>)>,
+        target: Target,
+        platform: Platform,
+        fallback_locale: Option<&str>,
+        message_format: MessageFormat,
+        validation: &ValidationConfig,
+    ) -> Result<Self> {
+        let name_collisions = validation.name_collisions;
+        let all_keys = projects
+            .iter()
+            .flat_map(|(_, keys)| keys)
+            .collect::<Vec<_>>();
+        let locales = find_locales(&all_keys)
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        if let Some(fallback_locale) = fallback_locale {
+            if !locales.iter().any(|locale| locale == fallback_locale) {
+                return Err(Error::msg(format!(
+                    "The fallback locale {:?} isn't used by any translation",
+                    fallback_locale
+                )));
+            }
+        }
+
+        let locale_names = locales.iter().map(String::as_str).collect::<Vec<_>>();
+        // Code refers to locales by name, so renaming them isn't an option.
+        if let Err(collisions) = names::unique_idents(
+            &locale_names,
+            |locale| locale.to_camel_case(),
+            &[],
+            NameCollisions::Error,
+            "Locale",
+        ) {
+            let report = collisions
+                .iter()
+                .map(|collision| format!("  {}", collision))
+                .collect::<Vec<_>>()
+                .join("\n");
+            return Err(Error::msg(format!(
+                "Locales collide after converting them to identifiers:\n{}",
+                report
+            )));
+        }
+
+        let mut missing = Vec::new();
+        let mut collisions = Vec::new();
+        let projects = projects
+            .into_iter()
+            .map(|(project, keys)| {
+                let mut keys = keys
+                    .iter()
+                    .map(|key| {
+                        let mut key = KeyTranslations::new(key, platform, message_format)?;
+                        if let Err(key_collisions) = key.resolve_argument_names(
+                            &format!("{}/{}: parameter", project.name, key.name),
+                            target,
+                            name_collisions,
+                        ) {
+                            collisions.extend(key_collisions);
+                        }
+                        key.check_selectors()?;
+
+                        let missing_locales = key.fill_missing(&locales, fallback_locale);
+                        if !missing_locales.is_empty() {
+                            missing.push(format!(
+                                "  {}/{}: {}",
+                                project.name,
+                                key.name,
+                                missing_locales.join(", ")
+                            ));
+                        }
+                        Ok(key)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
 
-    let ast = TopLevel { items };
-    Ok(to_code(ast))
+                let key_names = keys.iter().map(|key| key.name.as_str()).collect::<Vec<_>>();
+                match names::unique_idents(
+                    &key_names,
+                    |name| ident(name, target),
+                    &[],
+                    name_collisions,
+                    &format!("{}: method", project.name),
+                ) {
+                    Ok(method_names) => {
+                        for (key, method_name) in keys.iter_mut().zip(method_names) {
+                            key.method_name = method_name;
+                        }
+                    }
+                    Err(method_collisions) => collisions.extend(method_collisions),
+                }
+
+                Ok(ProjectTranslations {
+                    name: project.name,
+                    keys,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if !missing.is_empty() {
+            let hint = if fallback_locale.is_some() {
+                "The fallback locale is missing as well."
+            } else {
+                "Translate them or set `fallback_locale` in the config to use another locale's text."
+            };
+            let report = format!(
+                "Keys are missing translations:\n{}\n{}",
+                missing.join("\n"),
+                hint
+            );
+            if validation.strict {
+                return Err(Error::msg(report));
+            }
+            eprintln!("warning: {}\nThey're generated as empty texts.", report);
+        }
+
+        if !collisions.is_empty() {
+            return Err(names::collision_error(&collisions));
+        }
+
+        let selects = find_select_types(&projects)?;
+
+        Ok(Self {
+            locales,
+            selects,
+            projects,
+        })
+    }
 }
 
-fn locale_enum_variants(keys: &[&Key]) -> Vec<Item> {
-    let locales = find_locales(keys);
+impl Translations {
+    /// Whether any placeholder has to be formatted according to the locale.
+    pub fn uses_formatted_placeholders(&self) -> bool {
+        self.projects
+            .iter()
+            .flat_map(|project| &project.keys)
+            .flat_map(|key| &key.placeholders)
+            .any(|placeholder| placeholder.kind.is_formatted())
+    }
+}
+
+impl KeyTranslations {
+    fn new(key: &Key, platform: Platform, message_format: MessageFormat) -> Result<Self> {
+        let name = key.key_name.for_platform(platform);
+
+        // Lokalise has empty translations for locales the key hasn't been translated to yet.
+        let values = key
+            .translations
+            .iter()
+            .filter(|translation| !translation.translation.is_empty())
+            .map(|translation| {
+                let message =
+                    parse_translation(&translation.translation, key.is_plural, message_format)
+                        .map_err(|err| {
+                            Error::msg(format!(
+                                "Failed to parse the {} translation of {:?}: {}",
+                                translation.language_iso, name, err
+                            ))
+                        })?;
+
+                Ok(LocalizedMessage {
+                    locale: translation.language_iso.clone(),
+                    message,
+                    filled_in: false,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let placeholders = find_key_placeholders(&values);
+
+        Ok(Self {
+            name: name.to_string(),
+            method_name: name.to_mixed_case(),
+            plural: key.is_plural,
+            placeholders,
+            values,
+        })
+    }
+
+    /// Rename placeholders and arguments to identifiers.
+    ///
+    /// Names that become the same identifier, or `locale`, are returned as collisions or
+    /// disambiguated according to `policy`.
+    fn resolve_argument_names(
+        &mut self,
+        what: &str,
+        target: Target,
+        policy: NameCollisions,
+    ) -> Result<(), Vec<Collision>> {
+        let mut originals = Vec::<&str>::new();
+        for value in &self.values {
+            for name in value.message.argument_names() {
+                if !originals.contains(&name) {
+                    originals.push(name);
+                }
+            }
+        }
+
+        let idents = names::unique_idents(
+            &originals,
+            |name| ident(name, target),
+            &["locale"],
+            policy,
+            what,
+        )?;
+        let renames = originals
+            .into_iter()
+            .map(String::from)
+            .zip(idents)
+            .collect::<HashMap<_, _>>();
+
+        for value in &mut self.values {
+            value.message.rename_arguments(&renames);
+        }
+        self.placeholders = find_key_placeholders(&self.values);
+
+        Ok(())
+    }
+
+    /// Check that the arguments matched on aren't also placeholders of another kind.
+    fn check_selectors(&self) -> Result<()> {
+        for value in &self.values {
+            for selector in value.message.selectors() {
+                if let Some(placeholder) = self
+                    .placeholders
+                    .iter()
+                    .find(|p| p.name == selector.name && p.kind != selector.kind)
+                {
+                    return Err(Error::msg(format!(
+                        "Key {:?} matches on `{}`, which is also a `%{}` placeholder",
+                        self.name,
+                        selector.name,
+                        placeholder.kind.code()
+                    )));
+                }
+            }
+        }
 
-    locales
+        Ok(())
+    }
+
+    /// Add a copy of the `fallback_locale` text for each locale the key isn't translated to, or
+    /// an empty text if the key isn't translated to the fallback locale either.
+    ///
+    /// Returns the locales that got an empty text.
+    fn fill_missing(&mut self, locales: &[String], fallback_locale: Option<&str>) -> Vec<String> {
+        let missing_locales = locales
+            .iter()
+            .filter(|locale| !self.values.iter().any(|value| &value.locale == *locale))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let fallback = fallback_locale.and_then(|fallback_locale| {
+            self.values
+                .iter()
+                .find(|value| value.locale == fallback_locale)
+                .map(|value| value.message.clone())
+        });
+
+        let has_fallback = fallback.is_some();
+        let message = fallback.unwrap_or_else(|| Message::Text(Vec::new()));
+        for locale in &missing_locales {
+            self.values.push(LocalizedMessage {
+                locale: locale.clone(),
+                message: message.clone(),
+                filled_in: true,
+            });
+        }
+
+        if has_fallback {
+            Vec::new()
+        } else {
+            missing_locales
+        }
+    }
+
+    /// The parameters of the generated function.
+    ///
+    /// That is the placeholders, which are sorted by name, followed by the arguments that are
+    /// only matched on, such as the `count` of Lokalise plurals, in the order they're first
+    /// matched on. The parameters as a whole aren't sorted.
+    pub fn arguments(&self) -> Vec<Argument> {
+        let mut arguments = Vec::<Argument>::new();
+        for placeholder in &self.placeholders {
+            if !arguments.iter().any(|arg| arg.name == placeholder.name) {
+                arguments.push(Argument {
+                    name: placeholder.name.clone(),
+                    kind: placeholder.kind,
+                });
+            }
+        }
+
+        for value in &self.values {
+            for selector in value.message.selectors() {
+                if !arguments.iter().any(|arg| arg.name == selector.name) {
+                    arguments.push(selector);
+                }
+            }
+        }
+
+        arguments
+    }
+}
+
+/// The types of the select arguments, sorted by name.
+///
+/// Selects on arguments with the same name share a type with the cases of all of them.
+fn find_select_types(projects: &[ProjectTranslations]) -> Result<Vec<SelectType>> {
+    fn find_cases<'a>(message: &'a Message, types: &mut BTreeMap<String, Vec<&'a str>>) {
+        if let Message::Select {
+            argument, cases, ..
+        } = message
+        {
+            let type_cases = types.entry(select_type_name(argument)).or_default();
+            for (case, _) in cases {
+                if !type_cases.contains(&case.as_str()) {
+                    type_cases.push(case);
+                }
+            }
+        }
+
+        for branch in message.branches() {
+            find_cases(branch, types);
+        }
+    }
+
+    let mut types = BTreeMap::new();
+    for value in projects
+        .iter()
+        .flat_map(|project| &project.keys)
+        .flat_map(|key| &key.values)
+    {
+        find_cases(&value.message, &mut types);
+    }
+
+    types
         .into_iter()
-        .map(|locale| Item::Object {
-            case: false,
-            name: locale.to_camel_case(),
-            items: vec![],
-            methods: vec![],
-            super_type: Some("Locale".to_string()),
+        .map(|(name, cases)| {
+            if RESERVED_TYPE_NAMES.contains(&name.as_str()) {
+                return Err(Error::msg(format!(
+                    "Selects can't match on an argument named `{}` since its type would clash with the generated `{}`",
+                    name.to_mixed_case(),
+                    name
+                )));
+            }
+
+            let mut cases = cases.into_iter().map(String::from).collect::<Vec<_>>();
+            cases.push("other".to_string());
+
+            for (idx, case) in cases.iter().enumerate() {
+                if let Some(clash) = cases[..idx]
+                    .iter()
+                    .find(|other| other.to_camel_case() == case.to_camel_case())
+                {
+                    return Err(Error::msg(format!(
+                        "The cases {:?} and {:?} of `{}` both become `{}.{}`",
+                        clash,
+                        case,
+                        name.to_mixed_case(),
+                        name,
+                        case.to_camel_case()
+                    )));
+                }
+            }
+
+            Ok(SelectType { name, cases })
         })
         .collect()
 }
 
+/// Types generated regardless of the translations, which select types can't be named.
+const RESERVED_TYPE_NAMES: &[&str] = &["Locale", "Cardinality", "Money", "Format"];
+
+/// The `camelCase` identifier of a key or argument in the code generated for `target`.
+///
+/// Characters the target doesn't allow in identifiers, even quoted, are replaced by `_`, so
+/// names that only differ in those collide.
+fn ident(name: &str, target: Target) -> String {
+    let ident = name.to_mixed_case();
+    match target {
+        Target::Scala => ident,
+        Target::TypeScript => typescript::replace_invalid_chars(&ident),
+        Target::Kotlin => kotlin::replace_invalid_chars(&ident),
+        Target::Swift => swift::replace_invalid_chars(&ident),
+    }
+}
+
+/// The name of the type generated for the values of a select argument.
+pub fn select_type_name(argument: &str) -> String {
+    argument.to_camel_case()
+}
+
+fn locale_pattern(language_iso: &str) -> String {
+    member_pattern("Locale", &language_iso.to_camel_case())
+}
+
+/// A pattern matching the case object `member` of `object`.
+fn member_pattern(object: &str, member: &str) -> String {
+    Path {
+        segments: vec![Ident::new(object), Ident::new(member)],
+    }
+    .to_string()
+}
+
 fn find_locales<'a>(keys: &[&'a Key]) -> Vec<&'a str> {
     let mut names = keys
         .iter()
@@ -87,71 +801,25 @@ fn find_locales<'a>(keys: &[&'a Key]) -> Vec<&'a str> {
     names
 }
 
-fn translation_methods(keys: &[Key]) -> Result<Vec<MethodDef>> {
-    keys.iter().map(|key| translation_method(key)).collect()
+fn translation_methods(keys: &[KeyTranslations]) -> Vec<MethodDef> {
+    keys.iter().map(translation_method).collect()
 }
 
-fn translation_method(key: &Key) -> Result<MethodDef> {
-    if !key.key_name.all_same() {
-        return Err(Error::msg(format!(
-            "Key {:?} don't have identical key names for each platform. Dunno which one to pick.",
-            key
-        )));
-    }
-
-    if key.is_plural {
-        translation_method_with_cardinality(key)
-    } else {
-        translation_method_without_cardinality(key)
-    }
-}
-
-fn translation_method_with_cardinality(key: &Key) -> Result<MethodDef> {
-    let (placeholders, mut method_params) = build_method_params(key)?;
-    method_params.push(Param {
-        name: Ident::new("cardinality"),
-        ty: "Cardinality".to_string(),
-    });
+fn translation_method(key: &KeyTranslations) -> MethodDef {
+    let interpolate = !key.placeholders.is_empty();
 
     let locale_match_clauses = key
-        .translations
+        .values
         .iter()
-        .map(|translation| -> Result<_> {
-            let cases =
-                serde_json::from_str::<TranslationWithCardinality>(&translation.translation)?;
-
-            let singular_value =
-                build_translated_value_with_interpolations(&cases.one, &placeholders);
-            let plural_value =
-                build_translated_value_with_interpolations(&cases.other, &placeholders);
-
-            let cardinality_match_clauses = vec![
-                MatchClause {
-                    pattern: "Cardinality.Singular".to_string(),
-                    expr: singular_value,
-                },
-                MatchClause {
-                    pattern: "Cardinality.Plural".to_string(),
-                    expr: plural_value,
-                },
-            ];
-
-            Ok(MatchClause {
-                pattern: format!("Locale.{}", translation.language_iso.to_title_case()),
-                expr: Expr::Match {
-                    expr: Box::new(Expr::Var {
-                        name: Ident::new("cardinality"),
-                    }),
-                    clauses: cardinality_match_clauses,
-                },
-            })
+        .map(|value| MatchClause {
+            pattern: locale_pattern(&value.locale),
+            expr: message_expr(&value.message, interpolate),
         })
-        .collect::<Result<Vec<_>>>()?;
+        .collect::<Vec<_>>();
 
-    let name = Ident::new(key.key_name.ios.to_mixed_case());
-    Ok(MethodDef {
-        name,
-        params: method_params,
+    MethodDef {
+        name: Ident::new(&key.method_name),
+        params: build_method_params(key),
         implicit_params: vec![Param {
             name: Ident::new("locale"),
             ty: "Locale".to_string(),
@@ -163,97 +831,209 @@ fn translation_method_with_cardinality(key: &Key) -> Result<MethodDef> {
             clauses: locale_match_clauses,
         },
         return_type: "String".to_string(),
-        comment: Some(Comment::new(&key.key_name.ios)),
-    })
+        comment: Some(Comment::new(&key.name)),
+    }
 }
 
-fn translation_method_without_cardinality(key: &Key) -> Result<MethodDef> {
-    let (placeholders, method_params) = build_method_params(key)?;
+/// The text of a message, matching on the arguments picking its plural forms and cases.
+fn message_expr(message: &Message, interpolate: bool) -> Expr {
+    match message {
+        Message::Text(segments) => {
+            build_translated_value_with_interpolations(segments, interpolate)
+        }
+        Message::Plural {
+            argument,
+            exact,
+            forms,
+            other,
+        } => {
+            let mut cardinality_match_clauses = forms
+                .iter()
+                .map(|(category, form)| MatchClause {
+                    pattern: format!("Cardinality.{}", category.name()),
+                    expr: message_expr(form, interpolate),
+                })
+                .collect::<Vec<_>>();
+            cardinality_match_clauses.push(MatchClause {
+                pattern: "_".to_string(),
+                expr: message_expr(other, interpolate),
+            });
 
-    let locale_match_clauses = key
-        .translations
-        .iter()
-        .map(|translation| {
-            let value =
-                build_translated_value_with_interpolations(&translation.translation, &placeholders);
+            let cardinality_match = Expr::Match {
+                expr: Box::new(Expr::Call {
+                    function: Path::new("Cardinality"),
+                    args: vec![Expr::Var {
+                        name: Ident::new(argument),
+                    }],
+                }),
+                clauses: cardinality_match_clauses,
+            };
 
-            MatchClause {
-                pattern: format!("Locale.{}", translation.language_iso.to_title_case()),
-                expr: value,
+            if exact.is_empty() {
+                return cardinality_match;
             }
-        })
-        .collect::<Vec<_>>();
 
-    let name = Ident::new(key.key_name.ios.to_mixed_case());
+            let mut exact_match_clauses = exact
+                .iter()
+                .map(|(value, message)| MatchClause {
+                    pattern: value.to_string(),
+                    expr: message_expr(message, interpolate),
+                })
+                .collect::<Vec<_>>();
+            exact_match_clauses.push(MatchClause {
+                pattern: "_".to_string(),
+                expr: cardinality_match,
+            });
 
-    Ok(MethodDef {
-        name,
-        params: method_params,
-        implicit_params: vec![Param {
-            name: Ident::new("locale"),
-            ty: "Locale".to_string(),
-        }],
-        body: Expr::Match {
-            expr: Box::new(Expr::Var {
-                name: Ident::new("locale"),
-            }),
-            clauses: locale_match_clauses,
-        },
-        return_type: "String".to_string(),
-        comment: Some(Comment::new(&key.key_name.ios)),
-    })
+            Expr::Match {
+                expr: Box::new(Expr::Var {
+                    name: Ident::new(argument),
+                }),
+                clauses: exact_match_clauses,
+            }
+        }
+        Message::Select {
+            argument,
+            cases,
+            other,
+        } => {
+            let mut clauses = cases
+                .iter()
+                .map(|(case, message)| MatchClause {
+                    pattern: member_pattern(&select_type_name(argument), &case.to_camel_case()),
+                    expr: message_expr(message, interpolate),
+                })
+                .collect::<Vec<_>>();
+            clauses.push(MatchClause {
+                pattern: "_".to_string(),
+                expr: message_expr(other, interpolate),
+            });
+
+            Expr::Match {
+                expr: Box::new(Expr::Var {
+                    name: Ident::new(argument),
+                }),
+                clauses,
+            }
+        }
+    }
 }
 
+/// The JSON Lokalise stores plural translations as.
+///
+/// Which forms are present depends on the language, but `other` is always there.
 #[derive(Deserialize)]
 struct TranslationWithCardinality {
-    one: String,
+    zero: Option<String>,
+    one: Option<String>,
+    two: Option<String>,
+    few: Option<String>,
+    many: Option<String>,
     other: String,
 }
 
-fn build_method_params(key: &Key) -> Result<(Vec<Placeholder>, Vec<Param>)> {
-    let mut placeholders = key
-        .translations
-        .iter()
-        .map(|k| &k.translation)
-        .map(|t| find_placeholders(t))
-        .collect::<Result<Vec<_>>>()?
-        .into_iter()
-        .flatten()
-        .collect::<HashSet<_>>()
+impl TranslationWithCardinality {
+    /// The non-empty forms other than `other`.
+    fn forms(&self) -> Vec<(PluralCategory, &str)> {
+        vec![
+            (PluralCategory::Zero, &self.zero),
+            (PluralCategory::One, &self.one),
+            (PluralCategory::Two, &self.two),
+            (PluralCategory::Few, &self.few),
+            (PluralCategory::Many, &self.many),
+        ]
         .into_iter()
-        .collect::<Vec<_>>();
+        .filter_map(|(category, form)| match form {
+            Some(form) if !form.is_empty() => Some((category, form.as_str())),
+            _ => None,
+        })
+        .collect()
+    }
+}
 
-    placeholders.sort_unstable_by_key(|p| p.name.clone());
+fn build_method_params(key: &KeyTranslations) -> Vec<Param> {
+    key.arguments()
+        .into_iter()
+        .map(|argument| Param {
+            ty: match argument.kind {
+                PlaceholderKind::Select => Ident::new(select_type_name(&argument.name)).to_string(),
+                kind => scala_type(kind).to_string(),
+            },
+            name: Ident::new(argument.name),
+        })
+        .collect()
+}
 
-    let method_params = placeholders
+/// The placeholders used in any of the translations of a key, sorted by name.
+fn find_key_placeholders(values: &[LocalizedMessage]) -> Vec<Placeholder> {
+    let mut placeholders = values
         .iter()
-        .map(|placeholder| {
-            let ty = match placeholder.kind {
-                PlaceholderKind::String => "String",
-                PlaceholderKind::Integer => "Int",
-            }
-            .to_string();
-            Param {
-                name: Ident::new(&placeholder.name),
-                ty,
-            }
-        })
+        .flat_map(|value| value.message.placeholders())
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
         .collect::<Vec<_>>();
 
-    Ok((placeholders, method_params))
+    placeholders.sort_by_key(|p| p.name.clone());
+
+    placeholders
 }
 
-#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Placeholder {
-    name: String,
-    kind: PlaceholderKind,
-    matched: String,
+    pub name: String,
+    pub kind: PlaceholderKind,
+    pub matched: String,
 }
 
-#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
-enum PlaceholderKind {
+/// The type of value a placeholder is replaced with.
+///
+/// Everything but strings and integers is formatted according to the locale.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum PlaceholderKind {
+    /// `%s`
     String,
+    /// `%i` or `%d`
     Integer,
+    /// `%f`, or `%.2f` for a fixed number of decimals.
+    Float { decimals: Option<u32> },
+    /// `%decimal`, an exact decimal number such as a `BigDecimal`.
+    Decimal,
+    /// `%date`, a date without a time.
+    Date,
+    /// `%money`, an amount in some currency.
+    Money,
+    /// Not a placeholder but the argument of a select, typed as its `SelectType`.
+    Select,
+}
+
+impl PlaceholderKind {
+    /// Whether values are formatted according to the locale, rather than inserted as is.
+    pub fn is_formatted(self) -> bool {
+        match self {
+            PlaceholderKind::String | PlaceholderKind::Integer | PlaceholderKind::Select => false,
+            PlaceholderKind::Float { .. }
+            | PlaceholderKind::Decimal
+            | PlaceholderKind::Date
+            | PlaceholderKind::Money => true,
+        }
+    }
+
+    /// The kind as written in a placeholder, without the `%`.
+    pub fn code(self) -> String {
+        match self {
+            PlaceholderKind::String => "s".to_string(),
+            PlaceholderKind::Integer => "i".to_string(),
+            PlaceholderKind::Float { decimals: None } => "f".to_string(),
+            PlaceholderKind::Float {
+                decimals: Some(decimals),
+            } => format!(".{}f", decimals),
+            PlaceholderKind::Decimal => "decimal".to_string(),
+            PlaceholderKind::Date => "date".to_string(),
+            PlaceholderKind::Money => "money".to_string(),
+            PlaceholderKind::Select => "select".to_string(),
+        }
+    }
 }
 
 impl FromStr for PlaceholderKind {
@@ -262,90 +1042,455 @@ impl FromStr for PlaceholderKind {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "s" => Ok(PlaceholderKind::String),
-            "i" => Ok(PlaceholderKind::Integer),
-            _ => Err(Error::msg(format!("Unsupported placeholder kind: {:?}", s))),
+            "i" | "d" => Ok(PlaceholderKind::Integer),
+            "f" => Ok(PlaceholderKind::Float { decimals: None }),
+            "decimal" => Ok(PlaceholderKind::Decimal),
+            "date" => Ok(PlaceholderKind::Date),
+            "money" => Ok(PlaceholderKind::Money),
+            _ => {
+                let decimals = s
+                    .strip_prefix('.')
+                    .and_then(|s| s.strip_suffix('f'))
+                    .and_then(|decimals| decimals.parse().ok());
+                match decimals {
+                    Some(decimals) => Ok(PlaceholderKind::Float {
+                        decimals: Some(decimals),
+                    }),
+                    None => Err(Error::msg(format!("Unsupported placeholder kind: {:?}", s))),
+                }
+            }
         }
     }
 }
 
-pub fn find_placeholders(s: &str) -> Result<Vec<Placeholder>> {
-    lazy_static::lazy_static! {
-        static ref RE: Regex = Regex::new(
-            r#"\[%([si]):([^\]]+)\]"#
-        ).unwrap();
+lazy_static::lazy_static! {
+    static ref PLACEHOLDER: Regex = Regex::new(
+        r#"\[%([sidf]|\.\d+f|decimal|date|money):([^\]]+)\]"#
+    ).unwrap();
+}
+
+fn placeholder_from_captures(caps: &regex::Captures) -> Result<Placeholder> {
+    let raw_kind = &caps[1];
+    let kind = raw_kind.parse::<PlaceholderKind>()?;
+    let name = caps
+        .get(2)
+        .ok_or_else(|| Error::msg("placeholder regex didn't match"))?
+        .as_str()
+        .to_string();
+
+    let matched = caps
+        .get(0)
+        .ok_or_else(|| Error::msg("placeholder regex didn't match"))?
+        .as_str()
+        .to_string();
+
+    Ok(Placeholder {
+        name,
+        kind,
+        matched,
+    })
+}
+
+/// Parse a translation, which for plural keys is the JSON of its forms.
+fn parse_translation(translation: &str, plural: bool, format: MessageFormat) -> Result<Message> {
+    if !plural {
+        return parse_message(translation, format);
     }
 
-    RE.captures_iter(s)
-        .map(|caps| -> Result<_> {
-            let raw_kind = &caps[1];
-            let kind = raw_kind.parse::<PlaceholderKind>()?;
-            let name = caps
-                .get(2)
-                .ok_or_else(|| Error::msg("placeholder regex didn't match"))?
-                .as_str()
-                .to_mixed_case();
+    let cases = serde_json::from_str::<TranslationWithCardinality>(translation)?;
+    Ok(Message::Plural {
+        argument: "count".to_string(),
+        exact: Vec::new(),
+        forms: cases
+            .forms()
+            .into_iter()
+            .map(|(category, form)| Ok((category, parse_message(form, format)?)))
+            .collect::<Result<_>>()?,
+        other: Box::new(parse_message(&cases.other, format)?),
+    })
+}
 
-            let matched = caps
-                .get(0)
-                .ok_or_else(|| Error::msg("placeholder regex didn't match"))?
-                .as_str()
-                .to_string();
+/// Parse a translation written in `format`.
+fn parse_message(s: &str, format: MessageFormat) -> Result<Message> {
+    match format {
+        MessageFormat::Lokalise => Ok(Message::Text(parse_segments(s)?)),
+        MessageFormat::Icu => lower_icu(Vec::new(), &icu::parse(s)?),
+    }
+}
 
-            Ok(Placeholder {
-                name,
-                kind,
-                matched,
-            })
+/// Turn parsed ICU `nodes` into a message starting with `segments`.
+///
+/// Text before and after a plural or select is moved into each of its branches, so matches
+/// are only ever nested in other matches and the text ends up in the leaves.
+fn lower_icu(mut segments: Vec<Segment>, nodes: &[icu::Node]) -> Result<Message> {
+    for (idx, node) in nodes.iter().enumerate() {
+        let rest = &nodes[idx + 1..];
+        let branch = |pound: Option<&str>, branch: &[icu::Node]| {
+            let mut nodes = match pound {
+                Some(argument) => replace_pound(branch, argument),
+                None => branch.to_vec(),
+            };
+            nodes.extend_from_slice(rest);
+            lower_icu(segments.clone(), &nodes)
+        };
+
+        match node {
+            icu::Node::Text(text) => segments.extend(parse_segments(text)?),
+            icu::Node::Argument { name, kind } => {
+                segments.push(Segment::Placeholder(Placeholder {
+                    name: name.clone(),
+                    kind: *kind,
+                    matched: format!("{{{}}}", name),
+                }))
+            }
+            icu::Node::Pound => unreachable!("`#` should have been replaced by its argument"),
+            icu::Node::Plural {
+                argument,
+                exact,
+                forms,
+                other,
+            } => {
+                let pound = Some(argument.as_str());
+                return Ok(Message::Plural {
+                    argument: argument.clone(),
+                    exact: exact
+                        .iter()
+                        .map(|(value, nodes)| Ok((*value, branch(pound, nodes)?)))
+                        .collect::<Result<_>>()?,
+                    forms: forms
+                        .iter()
+                        .map(|(category, nodes)| Ok((*category, branch(pound, nodes)?)))
+                        .collect::<Result<_>>()?,
+                    other: Box::new(branch(pound, other)?),
+                });
+            }
+            icu::Node::Select {
+                argument,
+                cases,
+                other,
+            } => {
+                return Ok(Message::Select {
+                    argument: argument.clone(),
+                    cases: cases
+                        .iter()
+                        .map(|(case, nodes)| Ok((case.clone(), branch(None, nodes)?)))
+                        .collect::<Result<_>>()?,
+                    other: Box::new(branch(None, other)?),
+                });
+            }
+        }
+    }
+
+    Ok(Message::Text(merge_text(segments)))
+}
+
+/// Replace the `#`s of a plural branch with the plural's `argument`.
+///
+/// Nested plurals are left alone since `#` in those refers to their own argument.
+fn replace_pound(nodes: &[icu::Node], argument: &str) -> Vec<icu::Node> {
+    nodes
+        .iter()
+        .map(|node| match node {
+            icu::Node::Pound => icu::Node::Argument {
+                name: argument.to_string(),
+                kind: PlaceholderKind::Integer,
+            },
+            icu::Node::Select {
+                argument: select_argument,
+                cases,
+                other,
+            } => icu::Node::Select {
+                argument: select_argument.clone(),
+                cases: cases
+                    .iter()
+                    .map(|(case, nodes)| (case.clone(), replace_pound(nodes, argument)))
+                    .collect(),
+                other: replace_pound(other, argument),
+            },
+            node => node.clone(),
         })
-        .collect::<Result<Vec<_>>>()
+        .collect()
 }
 
-fn build_translated_value_with_interpolations(
-    translation: &str,
-    placeholders: &[Placeholder],
-) -> Expr {
-    let mut translation = translation.to_string();
-    for placeholder in placeholders {
-        translation =
-            translation.replace(&placeholder.matched, &format!("${{{}}}", placeholder.name));
+/// Join adjacent pieces of text, so the text around a plural or select becomes one piece with
+/// the text of each branch.
+fn merge_text(segments: Vec<Segment>) -> Vec<Segment> {
+    let mut merged = Vec::<Segment>::new();
+    for segment in segments {
+        match (merged.last_mut(), segment) {
+            (_, Segment::Text(text)) if text.is_empty() => {}
+            (Some(Segment::Text(previous)), Segment::Text(text)) => previous.push_str(&text),
+            (_, segment) => merged.push(segment),
+        }
     }
 
-    Expr::StrLit {
-        value: translation,
-        interpolate: !placeholders.is_empty(),
+    if merged.is_empty() {
+        merged.push(Segment::Text(String::new()));
     }
+    merged
 }
 
-fn hardcoded_items() -> Vec<Item> {
-    vec![
-        Item::Package {
-            segments: vec![Ident::new("dk"), Ident::new("undo"), Ident::new("i18n")],
+/// Split a translation into text and placeholders.
+fn parse_segments(s: &str) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut rest_start = 0;
+
+    for caps in PLACEHOLDER.captures_iter(s) {
+        let matched = caps
+            .get(0)
+            .ok_or_else(|| Error::msg("placeholder regex didn't match"))?;
+        if matched.start() > rest_start {
+            segments.push(Segment::Text(s[rest_start..matched.start()].to_string()));
+        }
+        segments.push(Segment::Placeholder(placeholder_from_captures(&caps)?));
+        rest_start = matched.end();
+    }
+
+    if rest_start < s.len() || segments.is_empty() {
+        segments.push(Segment::Text(s[rest_start..].to_string()));
+    }
+
+    Ok(segments)
+}
+
+fn build_translated_value_with_interpolations(segments: &[Segment], interpolate: bool) -> Expr {
+    let parts = segments
+        .iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => StrPart::Text(text.clone()),
+            Segment::Placeholder(placeholder) => StrPart::Expr(placeholder_expr(placeholder)),
+        })
+        .collect();
+
+    Expr::StrLit { parts, interpolate }
+}
+
+/// The value of a placeholder, formatted according to the locale if it isn't a string or
+/// integer.
+fn placeholder_expr(placeholder: &Placeholder) -> Expr {
+    let var = Expr::Var {
+        name: Ident::new(&placeholder.name),
+    };
+
+    let (function, args) = match placeholder.kind {
+        PlaceholderKind::String | PlaceholderKind::Integer | PlaceholderKind::Select => return var,
+        PlaceholderKind::Float { decimals: None } => ("number", vec![var]),
+        PlaceholderKind::Float {
+            decimals: Some(decimals),
+        } => ("fixed", vec![var, Expr::Raw(decimals.to_string())]),
+        PlaceholderKind::Decimal => ("decimal", vec![var]),
+        PlaceholderKind::Date => ("date", vec![var]),
+        PlaceholderKind::Money => ("money", vec![var]),
+    };
+
+    Expr::Call {
+        function: Path::new(&format!("Format.{}", function)),
+        args,
+    }
+}
+
+fn scala_type(kind: PlaceholderKind) -> &'static str {
+    match kind {
+        PlaceholderKind::String | PlaceholderKind::Select => "String",
+        PlaceholderKind::Integer => "Int",
+        PlaceholderKind::Float { .. } => "Double",
+        PlaceholderKind::Decimal => "BigDecimal",
+        PlaceholderKind::Date => "java.time.LocalDate",
+        PlaceholderKind::Money => "Money",
+    }
+}
+
+/// A type for the values of a select argument with a case per case.
+fn select_item(select: &SelectType) -> Item {
+    Item::Enum {
+        name: Ident::new(select.name.clone()),
+        cases: select
+            .cases
+            .iter()
+            .map(|case| Ident::new(case.to_camel_case()))
+            .collect(),
+        case_objects: true,
+        methods: vec![],
+    }
+}
+
+/// The `Money` type and the `Format` object used to format placeholders according to the
+/// locale.
+fn format_items(locales: &[String]) -> Vec<Item> {
+    let implicit_locale = || {
+        vec![Param {
+            name: Ident::new("locale"),
+            ty: "Locale".to_string(),
+        }]
+    };
+    let param = |name: &str, ty: &str| Param {
+        name: Ident::new(name),
+        ty: ty.to_string(),
+    };
+
+    let java_locale = MethodDef {
+        name: Ident::new("javaLocale"),
+        params: vec![param("locale", "Locale")],
+        implicit_params: vec![],
+        return_type: "java.util.Locale".to_string(),
+        body: Expr::Match {
+            expr: Box::new(Expr::Var {
+                name: Ident::new("locale"),
+            }),
+            clauses: locales
+                .iter()
+                .map(|locale| MatchClause {
+                    pattern: locale_pattern(locale),
+                    expr: Expr::Call {
+                        function: Path::new("java.util.Locale.forLanguageTag"),
+                        args: vec![Expr::StrLit {
+                            parts: vec![StrPart::Text(language_tag(locale))],
+                            interpolate: false,
+                        }],
+                    },
+                })
+                .collect(),
         },
-        Item::Trait {
-            name: "Cardinality".to_string(),
-            sealed: true,
+        comment: None,
+    };
+
+    let formatter = |name: &str, params: Vec<Param>, body: &str| MethodDef {
+        name: Ident::new(name),
+        params,
+        implicit_params: implicit_locale(),
+        return_type: "String".to_string(),
+        body: Expr::Raw(body.to_string()),
+        comment: None,
+    };
+
+    vec![
+        Item::CaseClass {
+            name: Ident::new("Money"),
+            params: vec![
+                param("amount", "BigDecimal"),
+                param("currency", "java.util.Currency"),
+            ],
         },
         Item::Object {
-            name: "Cardinality".to_string(),
             case: false,
-            methods: vec![],
-            items: vec![
-                Item::Object {
-                    name: "Singular".to_string(),
-                    case: true,
-                    methods: vec![],
-                    items: vec![],
-                    super_type: Some("Cardinality".to_string()),
-                },
-                Item::Object {
-                    name: "Plural".to_string(),
-                    case: true,
-                    methods: vec![],
-                    items: vec![],
-                    super_type: Some("Cardinality".to_string()),
-                },
+            name: Ident::new("Format"),
+            items: vec![],
+            methods: vec![
+                java_locale,
+                formatter(
+                    "number",
+                    vec![param("value", "Double")],
+                    "java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)",
+                ),
+                formatter(
+                    "fixed",
+                    vec![param("value", "Double"), param("decimals", "Int")],
+                    "s\"%,.${decimals}f\".formatLocal(javaLocale(locale), value)",
+                ),
+                formatter(
+                    "decimal",
+                    vec![param("value", "BigDecimal")],
+                    "val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))\n\
+                     format.setMinimumFractionDigits(value.scale max 0)\n\
+                     format.setMaximumFractionDigits(value.scale max 0)\n\
+                     format.format(value.bigDecimal)",
+                ),
+                formatter(
+                    "date",
+                    vec![param("value", "java.time.LocalDate")],
+                    "java.time.format.DateTimeFormatter\n\
+                     \x20 .ofLocalizedDate(java.time.format.FormatStyle.LONG)\n\
+                     \x20 .withLocale(javaLocale(locale))\n\
+                     \x20 .format(value)",
+                ),
+                formatter(
+                    "money",
+                    vec![param("value", "Money")],
+                    "val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))\n\
+                     format.setCurrency(value.currency)\n\
+                     format.format(value.amount.bigDecimal)",
+                ),
             ],
             super_type: None,
         },
     ]
 }
+
+/// The BCP 47 language tag of a Lokalise language code, such as `en-US` for `en_US`.
+pub fn language_tag(language_iso: &str) -> String {
+    language_iso.replace('_', "-")
+}
+
+fn hardcoded_items(config: &Config) -> Vec<Item> {
+    vec![Item::Package {
+        segments: config.package.iter().map(Ident::new).collect(),
+    }]
+}
+
+/// The `Cardinality` type with a case per CLDR plural category and an `apply` method picking
+/// the category of a count using the plural rules of each locale.
+fn cardinality_item(locales: &[String]) -> Result<Item> {
+    let locale_match_clauses = locales
+        .iter()
+        .map(|locale| -> Result<_> {
+            let mut clauses = plural_rules::rules_for(locale)?
+                .into_iter()
+                .map(|(category, condition)| MatchClause {
+                    pattern: format!("n if {}", condition.to_code("n")),
+                    expr: Expr::Var {
+                        name: Ident::new(category.name()),
+                    },
+                })
+                .collect::<Vec<_>>();
+            clauses.push(MatchClause {
+                pattern: "_".to_string(),
+                expr: Expr::Var {
+                    name: Ident::new(PluralCategory::Other.name()),
+                },
+            });
+
+            Ok(MatchClause {
+                pattern: locale_pattern(locale),
+                expr: Expr::Match {
+                    expr: Box::new(Expr::Call {
+                        function: Path::new("math.abs"),
+                        args: vec![Expr::Var {
+                            name: Ident::new("count"),
+                        }],
+                    }),
+                    clauses,
+                },
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let apply = MethodDef {
+        name: Ident::new("apply"),
+        params: vec![Param {
+            name: Ident::new("count"),
+            ty: "Int".to_string(),
+        }],
+        implicit_params: vec![Param {
+            name: Ident::new("locale"),
+            ty: "Locale".to_string(),
+        }],
+        body: Expr::Match {
+            expr: Box::new(Expr::Var {
+                name: Ident::new("locale"),
+            }),
+            clauses: locale_match_clauses,
+        },
+        return_type: "Cardinality".to_string(),
+        comment: None,
+    };
+
+    Ok(Item::Enum {
+        name: Ident::new("Cardinality"),
+        cases: PluralCategory::ALL
+            .iter()
+            .map(|category| Ident::new(category.name()))
+            .collect(),
+        case_objects: true,
+        methods: vec![apply],
+    })
+}
@@ -0,0 +1,340 @@
+use crate::lokalise_client::{Pagination, Platform, RetryPolicy};
+use crate::scala_ast::Dialect;
+use anyhow::{Error, Result};
+use serde::Deserialize;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tokio::fs;
+
+/// Name of the config file looked for in the root of the backend project.
+pub const FILE_NAME: &str = "i18n-code-gen.toml";
+
+/// Where the Scala code is written when `output` isn't set.
+const DEFAULT_OUTPUT: &str = "shared/src/main/scala/dk/undo/i18n/I18n.scala";
+
+/// Name of the top-level object when `object` isn't set.
+const DEFAULT_OBJECT: &str = "I18n";
+
+/// Configuration of what to generate and where to put it.
+///
+/// Fields missing from the config file fall back to the values in `Config::default`.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Names of the Lokalise projects to generate code for.
+    pub projects: Vec<String>,
+
+    /// Path of the generated file, relative to the directory containing the config file.
+    ///
+    /// Can't be set along with `split`. Defaults to `DEFAULT_OUTPUT`.
+    pub output: Option<PathBuf>,
+
+    /// Segments of the package the generated code is placed in.
+    pub package: Vec<String>,
+
+    /// Name of the top-level object containing an object per project.
+    ///
+    /// Can't be set along with `split`. Defaults to `DEFAULT_OBJECT`.
+    pub object: Option<String>,
+
+    /// Write the Scala code to a file per project rather than to `output`.
+    ///
+    /// Generated files in its `dir` that are no longer produced are deleted.
+    pub split: Option<SplitConfig>,
+
+    /// The platform whose key names are used for Scala.
+    ///
+    /// Defaults to `ios`, which is what Scala methods were always named after.
+    pub platform: Platform,
+
+    /// The version of Scala to generate code for.
+    pub dialect: Dialect,
+
+    /// Locale whose text is used for keys that haven't been translated to another locale.
+    ///
+    /// Without it missing translations are generated as empty texts, with a warning.
+    pub fallback_locale: Option<String>,
+
+    /// The syntax translations are written in.
+    pub message_format: MessageFormat,
+
+    /// Settings for talking to Lokalise.
+    pub lokalise: LokaliseConfig,
+
+    /// Checks run on the translations before generating code.
+    pub validation: ValidationConfig,
+
+    /// Settings for generating TypeScript.
+    pub typescript: Option<TypeScriptConfig>,
+
+    /// Settings for generating Kotlin.
+    pub kotlin: Option<KotlinConfig>,
+
+    /// Settings for generating Swift.
+    pub swift: Option<SwiftConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LokaliseConfig {
+    /// URL of the Lokalise API. Only worth changing to test against a mock server.
+    pub base_url: String,
+
+    /// How to retry requests that are rate limited or fail temporarily.
+    pub retry: RetryPolicy,
+
+    /// Number of keys to download per request.
+    pub page_size: u32,
+
+    /// Whether to page through keys with a cursor or page numbers.
+    pub pagination: Pagination,
+}
+
+impl Default for LokaliseConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://api.lokalise.com/api2".to_string(),
+            retry: RetryPolicy::default(),
+            page_size: 5000,
+            pagination: Pagination::Cursor,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ValidationConfig {
+    /// Locale whose translations define which placeholders a key has.
+    ///
+    /// Without it every translation is expected to use all placeholders used by any of them.
+    pub reference_locale: Option<String>,
+
+    /// Fail rather than warn when translations disagree about placeholders or are missing.
+    pub strict: bool,
+
+    /// What to do about names that become the same identifier.
+    pub name_collisions: NameCollisions,
+}
+
+/// What to do about names from Lokalise that become the same identifier in the generated code,
+/// such as the keys `policy_start` and `policyStart`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NameCollisions {
+    /// Fail, listing the names involved.
+    #[default]
+    Error,
+    /// Append a number to all but one of the names.
+    Disambiguate,
+}
+
+/// The syntax translations are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageFormat {
+    /// Plain text with Lokalise's universal placeholders, such as `[%s:name]`.
+    Lokalise,
+    /// ICU MessageFormat, such as `{count, plural, one {# item} other {# items}}`.
+    ///
+    /// Lokalise placeholders can still be used in the text.
+    Icu,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SplitConfig {
+    /// Directory the files are written to, relative to the directory containing the config file.
+    pub dir: PathBuf,
+
+    /// Name of each project's file. `{project}` is replaced by the name of the project's object.
+    pub project_file: String,
+
+    /// Name of the file containing `Locale`, `Cardinality` and the other types the projects
+    /// share.
+    pub shared_file: String,
+}
+
+impl Default for SplitConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("shared/src/main/scala/dk/undo/i18n"),
+            project_file: "{project}.scala".to_string(),
+            shared_file: "I18n.scala".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeScriptConfig {
+    /// Path of the generated file, relative to the directory containing the config file.
+    pub output: Option<PathBuf>,
+
+    /// The platform whose key names are used. Defaults to `web`.
+    pub platform: Option<Platform>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct KotlinConfig {
+    /// Path of the generated file, relative to the directory containing the config file.
+    pub output: Option<PathBuf>,
+
+    /// The platform whose key names are used. Defaults to `android`.
+    pub platform: Option<Platform>,
+
+    /// Segments of the package the generated code is placed in.
+    ///
+    /// Defaults to the package used for Scala.
+    pub package: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SwiftConfig {
+    /// Path of the generated file, relative to the directory containing the config file.
+    pub output: Option<PathBuf>,
+
+    /// The platform whose key names are used. Defaults to `ios`.
+    pub platform: Option<Platform>,
+}
+
+/// The language to generate code for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Scala,
+    TypeScript,
+    Kotlin,
+    Swift,
+}
+
+impl Target {
+    pub fn name(self) -> &'static str {
+        match self {
+            Target::Scala => "scala",
+            Target::TypeScript => "typescript",
+            Target::Kotlin => "kotlin",
+            Target::Swift => "swift",
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scala" => Ok(Target::Scala),
+            "typescript" => Ok(Target::TypeScript),
+            "kotlin" => Ok(Target::Kotlin),
+            "swift" => Ok(Target::Swift),
+            _ => Err(Error::msg(format!(
+                "Unsupported target {:?}. Expected \"scala\", \"typescript\", \"kotlin\" or \"swift\"",
+                s
+            ))),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            projects: vec!["Undo".to_string(), "Car".to_string()],
+            output: None,
+            package: vec!["dk".to_string(), "undo".to_string(), "i18n".to_string()],
+            object: None,
+            split: None,
+            platform: Platform::Ios,
+            dialect: Dialect::Scala2,
+            fallback_locale: None,
+            message_format: MessageFormat::Lokalise,
+            lokalise: LokaliseConfig::default(),
+            validation: ValidationConfig::default(),
+            typescript: None,
+            kotlin: None,
+            swift: None,
+        }
+    }
+}
+
+impl Config {
+    pub async fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .await
+            .map_err(|err| Error::msg(format!("Failed to read {}: {}", path.display(), err)))?;
+
+        let config = toml::from_str::<Self>(&contents)
+            .map_err(|err| Error::msg(format!("Failed to parse {}: {}", path.display(), err)))?;
+
+        if config.split.is_some() {
+            let unused = [
+                ("output", config.output.is_some()),
+                ("object", config.object.is_some()),
+            ]
+            .iter()
+            .filter(|(_, set)| *set)
+            .map(|(field, _)| format!("`{}`", field))
+            .collect::<Vec<_>>();
+            if !unused.is_empty() {
+                return Err(Error::msg(format!(
+                    "{} in {} can't be used with `[split]`, which writes a file per project to \
+                     its `dir` instead",
+                    unused.join(" and "),
+                    path.display()
+                )));
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Where to write the code generated for `target`.
+    pub fn output_path(&self, target: Target) -> Result<&Path> {
+        let output = match target {
+            Target::Scala => Some(
+                self.output
+                    .as_deref()
+                    .unwrap_or_else(|| DEFAULT_OUTPUT.as_ref()),
+            ),
+            Target::TypeScript => self.typescript.as_ref().and_then(|c| c.output.as_deref()),
+            Target::Kotlin => self.kotlin.as_ref().and_then(|c| c.output.as_deref()),
+            Target::Swift => self.swift.as_ref().and_then(|c| c.output.as_deref()),
+        };
+
+        output.ok_or_else(|| {
+            Error::msg(format!(
+                "No `output` set in the `[{}]` config section",
+                target.name()
+            ))
+        })
+    }
+
+    /// Name of the top-level object containing an object per project.
+    pub fn object(&self) -> &str {
+        self.object.as_deref().unwrap_or(DEFAULT_OBJECT)
+    }
+
+    /// The platform whose key names are used for `target`.
+    pub fn platform(&self, target: Target) -> Platform {
+        match target {
+            Target::Scala => self.platform,
+            Target::TypeScript => self
+                .typescript
+                .as_ref()
+                .and_then(|c| c.platform)
+                .unwrap_or(Platform::Web),
+            Target::Kotlin => self
+                .kotlin
+                .as_ref()
+                .and_then(|c| c.platform)
+                .unwrap_or(Platform::Android),
+            Target::Swift => self
+                .swift
+                .as_ref()
+                .and_then(|c| c.platform)
+                .unwrap_or(Platform::Ios),
+        }
+    }
+}
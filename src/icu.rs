@@ -0,0 +1,483 @@
+use crate::code_gen::PlaceholderKind;
+use crate::plural_rules::PluralCategory;
+use anyhow::{Error, Result};
+
+/// A piece of an ICU MessageFormat message.
+///
+/// See <https://unicode-org.github.io/icu/userguide/format_parse/messages/>
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// Literal text, with ICU's apostrophe quoting resolved.
+    Text(String),
+    /// `{name}`, `{name, number}`, `{name, number, integer}` or `{name, date}`.
+    Argument { name: String, kind: PlaceholderKind },
+    /// `#` in a plural branch, standing for the number the branch was picked by.
+    Pound,
+    /// `{name, plural, =0 {...} one {...} other {...}}`
+    Plural {
+        argument: String,
+        exact: Vec<(u32, Vec<Node>)>,
+        forms: Vec<(PluralCategory, Vec<Node>)>,
+        other: Vec<Node>,
+    },
+    /// `{name, select, male {...} female {...} other {...}}`
+    Select {
+        argument: String,
+        cases: Vec<(String, Vec<Node>)>,
+        other: Vec<Node>,
+    },
+}
+
+/// The selector of a plural or select branch and its message.
+type Branch = (String, Vec<Node>);
+
+/// Parse an ICU MessageFormat message.
+pub fn parse(message: &str) -> Result<Vec<Node>> {
+    let mut parser = Parser {
+        chars: message.chars().collect(),
+        pos: 0,
+    };
+
+    let nodes = parser.message(false)?;
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("Unexpected `}`"));
+    }
+
+    Ok(nodes)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    /// The nodes up to the end of the message or the `}` closing the enclosing branch.
+    ///
+    /// `#` is only special inside a plural branch.
+    fn message(&mut self, in_plural: bool) -> Result<Vec<Node>> {
+        let mut nodes = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '}' => break,
+                '{' => {
+                    flush_text(&mut text, &mut nodes);
+                    nodes.push(self.argument(in_plural)?);
+                }
+                '#' if in_plural => {
+                    flush_text(&mut text, &mut nodes);
+                    nodes.push(Node::Pound);
+                    self.pos += 1;
+                }
+                '\'' => self.quoted(in_plural, &mut text),
+                c => {
+                    text.push(c);
+                    self.pos += 1;
+                }
+            }
+        }
+
+        flush_text(&mut text, &mut nodes);
+        Ok(nodes)
+    }
+
+    /// An apostrophe, which either escapes itself or quotes syntax characters.
+    ///
+    /// Like ICU, an apostrophe not followed by a character that needs quoting is literal, so
+    /// "don't" doesn't have to be written as "don''t".
+    fn quoted(&mut self, in_plural: bool, text: &mut String) {
+        self.pos += 1;
+
+        match self.peek() {
+            Some('\'') => {
+                text.push('\'');
+                self.pos += 1;
+                return;
+            }
+            Some('{') | Some('}') | Some('|') => {}
+            Some('#') if in_plural => {}
+            _ => {
+                text.push('\'');
+                return;
+            }
+        }
+
+        // Everything up to the next lone apostrophe, or the end of the message, is literal.
+        while let Some(c) = self.peek() {
+            self.pos += 1;
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    text.push('\'');
+                    self.pos += 1;
+                } else {
+                    return;
+                }
+            } else {
+                text.push(c);
+            }
+        }
+    }
+
+    /// An argument, which is a plural or select if it has branches.
+    ///
+    /// `#` in a select inside a plural branch still refers to the plural's number.
+    fn argument(&mut self, in_plural: bool) -> Result<Node> {
+        self.expect('{')?;
+        let name = self.word()?;
+
+        if self.eat('}') {
+            return Ok(Node::Argument {
+                name,
+                kind: PlaceholderKind::String,
+            });
+        }
+        self.expect(',')?;
+
+        self.skip_whitespace();
+        let start = self.pos;
+        let ty = self.word()?;
+        match ty.as_str() {
+            "number" | "date" => {
+                let style = if self.eat(',') {
+                    Some(self.word()?)
+                } else {
+                    None
+                };
+                self.expect('}')?;
+
+                let kind = match (ty.as_str(), style.as_deref()) {
+                    ("number", None) => PlaceholderKind::Float { decimals: None },
+                    ("number", Some("integer")) => PlaceholderKind::Integer,
+                    ("date", None) | ("date", Some("long")) => PlaceholderKind::Date,
+                    (_, Some(style)) => {
+                        return Err(
+                            self.error_at(start, &format!("Unsupported {} style `{}`", ty, style))
+                        )
+                    }
+                    (_, None) => unreachable!(),
+                };
+                Ok(Node::Argument { name, kind })
+            }
+            "plural" => {
+                self.expect(',')?;
+                let (branches, other) = self.branches(true)?;
+
+                let mut exact = Vec::new();
+                let mut forms = Vec::new();
+                for (selector, nodes) in branches {
+                    if let Some(number) = selector.strip_prefix('=') {
+                        let number = number.parse().map_err(|_| {
+                            self.error_at(start, &format!("Invalid plural selector `{}`", selector))
+                        })?;
+                        exact.push((number, nodes));
+                    } else {
+                        let category = PluralCategory::ALL
+                            .iter()
+                            .find(|category| category.name().to_lowercase() == selector)
+                            .ok_or_else(|| {
+                                self.error_at(
+                                    start,
+                                    &format!("Unknown plural category `{}`", selector),
+                                )
+                            })?;
+                        forms.push((*category, nodes));
+                    }
+                }
+
+                Ok(Node::Plural {
+                    argument: name,
+                    exact,
+                    forms,
+                    other,
+                })
+            }
+            "select" => {
+                self.expect(',')?;
+                let (cases, other) = self.branches(in_plural)?;
+                Ok(Node::Select {
+                    argument: name,
+                    cases,
+                    other,
+                })
+            }
+            _ => Err(self.error_at(start, &format!("Unsupported argument type `{}`", ty))),
+        }
+    }
+
+    /// The branches of a plural or select argument up to and including its closing `}`.
+    ///
+    /// Returns the branches other than `other`, which is required, in the order they're
+    /// written.
+    fn branches(&mut self, in_plural: bool) -> Result<(Vec<Branch>, Vec<Node>)> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let mut branches = Vec::<Branch>::new();
+        let mut other = None;
+
+        loop {
+            self.skip_whitespace();
+            if self.eat('}') {
+                break;
+            }
+
+            let selector_start = self.pos;
+            let selector = self.word()?;
+            if selector.starts_with("offset:") {
+                return Err(self.error_at(selector_start, "Plural offsets aren't supported"));
+            }
+
+            self.expect('{')?;
+            let nodes = self.message(in_plural)?;
+            self.expect('}')?;
+
+            if other.is_some() && selector == "other"
+                || branches.iter().any(|(existing, _)| *existing == selector)
+            {
+                return Err(self.error_at(
+                    selector_start,
+                    &format!("Duplicate selector `{}`", selector),
+                ));
+            }
+
+            if selector == "other" {
+                other = Some(nodes);
+            } else {
+                branches.push((selector, nodes));
+            }
+        }
+
+        let other =
+            other.ok_or_else(|| self.error_at(start, "Missing the required `other` branch"))?;
+        Ok((branches, other))
+    }
+
+    /// A name, type or selector, surrounded by optional whitespace.
+    fn word(&mut self) -> Result<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || matches!(c, '{' | '}' | ',' | '#' | '\'') {
+                break;
+            }
+            self.pos += 1;
+        }
+
+        if self.pos == start {
+            return Err(self.error("Expected a name"));
+        }
+        let word = self.chars[start..self.pos].iter().collect();
+        self.skip_whitespace();
+        Ok(word)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// Skip over `c` if it's next.
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected `{}`", c)))
+        }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        self.error_at(self.pos, message)
+    }
+
+    fn error_at(&self, pos: usize, message: &str) -> Error {
+        Error::msg(format!(
+            "{} at character {} of {:?}",
+            message,
+            pos,
+            self.text()
+        ))
+    }
+
+    fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+}
+
+fn flush_text(text: &mut String, nodes: &mut Vec<Node>) {
+    if !text.is_empty() {
+        nodes.push(Node::Text(std::mem::take(text)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> Node {
+        Node::Text(s.to_string())
+    }
+
+    fn error(message: &str) -> String {
+        parse(message).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parses_arguments() {
+        assert_eq!(
+            parse("Hi {name}, {count, number, integer} {rating, number} {start, date}").unwrap(),
+            vec![
+                text("Hi "),
+                Node::Argument {
+                    name: "name".to_string(),
+                    kind: PlaceholderKind::String,
+                },
+                text(", "),
+                Node::Argument {
+                    name: "count".to_string(),
+                    kind: PlaceholderKind::Integer,
+                },
+                text(" "),
+                Node::Argument {
+                    name: "rating".to_string(),
+                    kind: PlaceholderKind::Float { decimals: None },
+                },
+                text(" "),
+                Node::Argument {
+                    name: "start".to_string(),
+                    kind: PlaceholderKind::Date,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn apostrophes_quote_syntax_characters() {
+        assert_eq!(parse("don't").unwrap(), vec![text("don't")]);
+        assert_eq!(parse("it''s").unwrap(), vec![text("it's")]);
+        assert_eq!(
+            parse("'{name}' is literal").unwrap(),
+            vec![text("{name} is literal")]
+        );
+        assert_eq!(parse("'{it''s}'").unwrap(), vec![text("{it's}")]);
+        assert_eq!(
+            parse("'{unterminated").unwrap(),
+            vec![text("{unterminated")]
+        );
+        assert_eq!(parse("'#' and #").unwrap(), vec![text("'#' and #")]);
+    }
+
+    #[test]
+    fn pound_is_the_count_only_in_plurals() {
+        assert_eq!(
+            parse("#{n, plural, other {# '#'}}").unwrap(),
+            vec![
+                text("#"),
+                Node::Plural {
+                    argument: "n".to_string(),
+                    exact: vec![],
+                    forms: vec![],
+                    other: vec![Node::Pound, text(" #")],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_plurals_and_selects_nested_in_each_other() {
+        let message = "{n, plural, =0 {none} one {{g, select, female {her #} other {their #}}} \
+                       other {{g, select, other {# of theirs}}}}";
+
+        assert_eq!(
+            parse(message).unwrap(),
+            vec![Node::Plural {
+                argument: "n".to_string(),
+                exact: vec![(0, vec![text("none")])],
+                forms: vec![(
+                    PluralCategory::One,
+                    vec![Node::Select {
+                        argument: "g".to_string(),
+                        cases: vec![("female".to_string(), vec![text("her "), Node::Pound])],
+                        other: vec![text("their "), Node::Pound],
+                    }],
+                )],
+                other: vec![Node::Select {
+                    argument: "g".to_string(),
+                    cases: vec![],
+                    other: vec![Node::Pound, text(" of theirs")],
+                }],
+            }]
+        );
+    }
+
+    #[test]
+    fn pound_is_text_in_selects_outside_plurals() {
+        assert_eq!(
+            parse("{g, select, other {#1}}").unwrap(),
+            vec![Node::Select {
+                argument: "g".to_string(),
+                cases: vec![],
+                other: vec![text("#1")],
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_unterminated_braces() {
+        assert_eq!(
+            error("Hi {name"),
+            r#"Expected `,` at character 8 of "Hi {name""#
+        );
+        assert_eq!(
+            error("{n, plural, other {#}"),
+            r#"Expected a name at character 21 of "{n, plural, other {#}""#
+        );
+        assert_eq!(
+            error("{n, plural, other {#"),
+            r#"Expected `}` at character 20 of "{n, plural, other {#""#
+        );
+        assert_eq!(error("a}b"), r#"Unexpected `}` at character 1 of "a}b""#);
+    }
+
+    #[test]
+    fn reports_where_unsupported_syntax_is() {
+        assert_eq!(
+            error("{n, number, percent}"),
+            r#"Unsupported number style `percent` at character 4 of "{n, number, percent}""#
+        );
+        assert_eq!(
+            error("{n, plural, fwe {#} other {#}}"),
+            r#"Unknown plural category `fwe` at character 4 of "{n, plural, fwe {#} other {#}}""#
+        );
+        assert_eq!(
+            error("Hi {t, time}"),
+            r#"Unsupported argument type `time` at character 7 of "Hi {t, time}""#
+        );
+        assert_eq!(
+            error("{n, plural, offset:1 other {#}}"),
+            r#"Plural offsets aren't supported at character 12 of "{n, plural, offset:1 other {#}}""#
+        );
+        assert_eq!(
+            error("{n, plural, few {#} other {#} few {#}}"),
+            r#"Duplicate selector `few` at character 30 of "{n, plural, few {#} other {#} few {#}}""#
+        );
+        assert_eq!(
+            error("{g, select, female {her}}"),
+            r#"Missing the required `other` branch at character 12 of "{g, select, female {her}}""#
+        );
+    }
+}
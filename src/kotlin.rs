@@ -0,0 +1,442 @@
+use crate::code_gen::{
+    language_tag, select_type_name, KeyTranslations, Message, PlaceholderKind, Segment, SelectType,
+    Translations,
+};
+use crate::config::Config;
+use crate::plural_rules::{self, PluralCategory};
+use anyhow::Result;
+use heck::CamelCase;
+use std::fmt::Write;
+
+/// Generate a Kotlin file with an `object` containing a function per key.
+///
+/// Each project becomes a nested object of functions taking the locale as the first argument
+/// followed by the placeholders.
+pub fn generate_code(translations: &Translations, config: &Config) -> Result<String> {
+    let mut out = String::new();
+
+    let package = config
+        .kotlin
+        .as_ref()
+        .and_then(|kotlin| kotlin.package.as_ref())
+        .unwrap_or(&config.package);
+
+    writeln!(
+        out,
+        "// This file is generated by i18n-code-gen. Don't edit it by hand."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "package {}",
+        package
+            .iter()
+            .map(|segment| escape_ident(segment))
+            .collect::<Vec<_>>()
+            .join(".")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    write_locale_class(&mut out, &translations.locales);
+    writeln!(out).unwrap();
+    write_cardinality_class(&mut out, &translations.locales)?;
+    writeln!(out).unwrap();
+
+    for select in &translations.selects {
+        write_select_class(&mut out, select);
+        writeln!(out).unwrap();
+    }
+
+    if translations.uses_formatted_placeholders() {
+        write_format_object(&mut out, &translations.locales);
+        writeln!(out).unwrap();
+    }
+
+    writeln!(out, "object {} {{", escape_ident(config.object())).unwrap();
+    for (idx, project) in translations.projects.iter().enumerate() {
+        if idx != 0 {
+            writeln!(out).unwrap();
+        }
+        writeln!(
+            out,
+            "    object {} {{",
+            escape_ident(&project.name.to_camel_case())
+        )
+        .unwrap();
+
+        for (idx, key) in project.keys.iter().enumerate() {
+            if idx != 0 {
+                writeln!(out).unwrap();
+            }
+            write_function(&mut out, key);
+        }
+
+        writeln!(out, "    }}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    Ok(out)
+}
+
+fn write_locale_class(out: &mut String, locales: &[String]) {
+    writeln!(out, "sealed class Locale {{").unwrap();
+    for locale in locales {
+        writeln!(out, "    object {} : Locale()", locale_name(locale)).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+fn write_select_class(out: &mut String, select: &SelectType) {
+    writeln!(out, "sealed class {} {{", escape_ident(&select.name)).unwrap();
+    for case in &select.cases {
+        writeln!(
+            out,
+            "    object {} : {}()",
+            escape_ident(&case.to_camel_case()),
+            escape_ident(&select.name)
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+}
+
+fn write_cardinality_class(out: &mut String, locales: &[String]) -> Result<()> {
+    writeln!(out, "sealed class Cardinality {{").unwrap();
+    for category in PluralCategory::ALL.iter() {
+        writeln!(out, "    object {} : Cardinality()", category.name()).unwrap();
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "    companion object {{").unwrap();
+    writeln!(
+        out,
+        "        fun of(locale: Locale, count: Int): Cardinality {{"
+    )
+    .unwrap();
+    writeln!(out, "            val n = Math.abs(count)").unwrap();
+    writeln!(out, "            return when (locale) {{").unwrap();
+    for locale in locales {
+        writeln!(
+            out,
+            "                Locale.{} -> when {{",
+            locale_name(locale)
+        )
+        .unwrap();
+        for (category, condition) in plural_rules::rules_for(locale)? {
+            writeln!(
+                out,
+                "                    {} -> {}",
+                condition.to_code("n"),
+                category.name()
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "                    else -> {}",
+            PluralCategory::Other.name()
+        )
+        .unwrap();
+        writeln!(out, "                }}").unwrap();
+    }
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    Ok(())
+}
+
+/// The `Money` class and the `Format` object formatting placeholders according to the locale.
+fn write_format_object(out: &mut String, locales: &[String]) {
+    writeln!(
+        out,
+        "data class Money(val amount: java.math.BigDecimal, val currency: java.util.Currency)"
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "object Format {{").unwrap();
+    writeln!(
+        out,
+        "    fun javaLocale(locale: Locale): java.util.Locale = when (locale) {{"
+    )
+    .unwrap();
+    for locale in locales {
+        writeln!(
+            out,
+            "        Locale.{} -> java.util.Locale.forLanguageTag(\"{}\")",
+            locale_name(locale),
+            language_tag(locale)
+        )
+        .unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+    out.push_str(
+        r#"
+    fun number(locale: Locale, value: Double): String =
+        java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+
+    fun fixed(locale: Locale, value: Double, decimals: Int): String =
+        String.format(javaLocale(locale), "%,.${decimals}f", value)
+
+    fun decimal(locale: Locale, value: java.math.BigDecimal): String {
+        val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+        format.minimumFractionDigits = maxOf(value.scale(), 0)
+        format.maximumFractionDigits = maxOf(value.scale(), 0)
+        return format.format(value)
+    }
+
+    fun date(locale: Locale, value: java.time.LocalDate): String =
+        java.time.format.DateTimeFormatter
+            .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+            .withLocale(javaLocale(locale))
+            .format(value)
+
+    fun money(locale: Locale, value: Money): String {
+        val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+        format.currency = value.currency
+        return format.format(value.amount)
+    }
+}
+"#,
+    );
+}
+
+fn write_function(out: &mut String, key: &KeyTranslations) {
+    let mut params = vec!["locale: Locale".to_string()];
+    params.extend(key.arguments().into_iter().map(|argument| {
+        let ty = match argument.kind {
+            PlaceholderKind::Select => {
+                return format!(
+                    "{}: {}",
+                    escape_ident(&argument.name),
+                    escape_ident(&select_type_name(&argument.name))
+                )
+            }
+            PlaceholderKind::String => "String",
+            PlaceholderKind::Integer => "Int",
+            PlaceholderKind::Float { .. } => "Double",
+            PlaceholderKind::Decimal => "java.math.BigDecimal",
+            PlaceholderKind::Date => "java.time.LocalDate",
+            PlaceholderKind::Money => "Money",
+        };
+        format!("{}: {}", escape_ident(&argument.name), ty)
+    }));
+
+    writeln!(out, "        // {}", key.name).unwrap();
+    writeln!(
+        out,
+        "        fun {}({}): String = when (locale) {{",
+        escape_ident(&key.method_name),
+        params.join(", ")
+    )
+    .unwrap();
+
+    for value in &key.values {
+        writeln!(
+            out,
+            "            Locale.{} -> {}",
+            locale_name(&value.locale),
+            message_expr(&value.message, 12)
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "        }}").unwrap();
+}
+
+/// An expression for the text of a message, using `when` on the arguments picking its plural
+/// forms and cases.
+fn message_expr(message: &Message, indent: usize) -> String {
+    let pad = " ".repeat(indent);
+    let mut out = String::new();
+    match message {
+        Message::Text(segments) => out.push_str(&string_lit(segments)),
+        Message::Plural {
+            argument,
+            exact,
+            forms,
+            other,
+        } => {
+            let argument = escape_ident(argument);
+            let mut indent = indent;
+            if !exact.is_empty() {
+                writeln!(out, "when ({}) {{", argument).unwrap();
+                for (value, message) in exact {
+                    writeln!(
+                        out,
+                        "{}    {} -> {}",
+                        pad,
+                        value,
+                        message_expr(message, indent + 4)
+                    )
+                    .unwrap();
+                }
+                write!(out, "{}    else -> ", pad).unwrap();
+                indent += 4;
+            }
+
+            let inner_pad = " ".repeat(indent);
+            writeln!(out, "when (Cardinality.of(locale, {})) {{", argument).unwrap();
+            for (category, form) in forms {
+                writeln!(
+                    out,
+                    "{}    Cardinality.{} -> {}",
+                    inner_pad,
+                    category.name(),
+                    message_expr(form, indent + 4)
+                )
+                .unwrap();
+            }
+            writeln!(
+                out,
+                "{}    else -> {}",
+                inner_pad,
+                message_expr(other, indent + 4)
+            )
+            .unwrap();
+            write!(out, "{}}}", inner_pad).unwrap();
+
+            if !exact.is_empty() {
+                write!(out, "\n{}}}", pad).unwrap();
+            }
+        }
+        Message::Select {
+            argument,
+            cases,
+            other,
+        } => {
+            let ty = escape_ident(&select_type_name(argument));
+            writeln!(out, "when ({}) {{", escape_ident(argument)).unwrap();
+            for (case, message) in cases {
+                writeln!(
+                    out,
+                    "{}    {}.{} -> {}",
+                    pad,
+                    ty,
+                    escape_ident(&case.to_camel_case()),
+                    message_expr(message, indent + 4)
+                )
+                .unwrap();
+            }
+            writeln!(
+                out,
+                "{}    else -> {}",
+                pad,
+                message_expr(other, indent + 4)
+            )
+            .unwrap();
+            write!(out, "{}}}", pad).unwrap();
+        }
+    }
+    out
+}
+
+fn locale_name(locale: &str) -> String {
+    escape_ident(&locale.to_camel_case())
+}
+
+/// A string literal using string templates for the placeholders.
+fn string_lit(segments: &[Segment]) -> String {
+    let mut out = String::from("\"");
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => {
+                for c in text.chars() {
+                    match c {
+                        '\\' => out.push_str("\\\\"),
+                        '"' => out.push_str("\\\""),
+                        '$' => out.push_str("\\$"),
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        c => out.push(c),
+                    }
+                }
+            }
+            Segment::Placeholder(placeholder) => {
+                let name = escape_ident(&placeholder.name);
+                match placeholder.kind {
+                    PlaceholderKind::String
+                    | PlaceholderKind::Integer
+                    | PlaceholderKind::Select => write!(out, "${{{}}}", name).unwrap(),
+                    PlaceholderKind::Float { decimals: None } => {
+                        write!(out, "${{Format.number(locale, {})}}", name).unwrap()
+                    }
+                    PlaceholderKind::Float {
+                        decimals: Some(decimals),
+                    } => write!(out, "${{Format.fixed(locale, {}, {})}}", name, decimals).unwrap(),
+                    PlaceholderKind::Decimal => {
+                        write!(out, "${{Format.decimal(locale, {})}}", name).unwrap()
+                    }
+                    PlaceholderKind::Date => {
+                        write!(out, "${{Format.date(locale, {})}}", name).unwrap()
+                    }
+                    PlaceholderKind::Money => {
+                        write!(out, "${{Format.money(locale, {})}}", name).unwrap()
+                    }
+                }
+            }
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Replace the characters that can't be part of a name even in backticks with `_`.
+///
+/// Those are the characters the JVM doesn't allow in names, like the `.` in `policy.start`, and
+/// whitespace, which Android doesn't allow before API level 30.
+pub fn replace_invalid_chars(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            '.' | ';' | '[' | ']' | '/' | '<' | '>' | ':' | '\\' | '`' => '_',
+            c if c.is_whitespace() || c.is_control() => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// Quote names that aren't plain Kotlin identifiers, like keywords, with backticks.
+fn escape_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as",
+        "break",
+        "class",
+        "continue",
+        "do",
+        "else",
+        "false",
+        "for",
+        "fun",
+        "if",
+        "in",
+        "interface",
+        "is",
+        "null",
+        "object",
+        "package",
+        "return",
+        "super",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typealias",
+        "typeof",
+        "val",
+        "var",
+        "when",
+        "while",
+    ];
+
+    let is_plain = name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+    if KEYWORDS.contains(&name) || !is_plain {
+        format!("`{}`", name)
+    } else {
+        name.to_string()
+    }
+}
@@ -1,45 +1,156 @@
-use anyhow::Result;
-use reqwest::{Client, Url};
-use serde::{de::DeserializeOwned, Deserialize};
+use crate::config::LokaliseConfig;
+use anyhow::{Error, Result};
+use reqwest::{header::HeaderMap, Client, StatusCode, Url};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    error, fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::time::delay_for;
+
+/// How much of a response body to include in errors.
+const MAX_BODY_LEN: usize = 1000;
 
-#[derive(Debug)]
 pub struct LokaliseClient {
     api_token: String,
+    base_url: String,
     client: Client,
+    retry_policy: RetryPolicy,
+    page_size: u32,
+    pagination: Pagination,
+}
+
+/// How to page through keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Pagination {
+    /// Follow the cursor Lokalise returns with each page. Doesn't slow down for large projects,
+    /// but Lokalise doesn't report the total number of keys then.
+    Cursor,
+    /// Request numbered pages. The number of keys downloaded is checked against the total
+    /// Lokalise reports.
+    Offset,
+}
+
+/// How to retry requests that are rate limited or fail in a way that might be temporary.
+///
+/// Rate limited requests are retried after the time Lokalise asks for, other failures after a
+/// delay doubling with each attempt.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryPolicy {
+    /// How many times to retry a request before giving up.
+    pub max_retries: u32,
+
+    /// Milliseconds to wait before the first retry.
+    pub initial_backoff_ms: u64,
+
+    /// The longest to wait between retries, in milliseconds, unless Lokalise asks for longer.
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff_ms
+            .saturating_mul(2u64.saturating_pow(attempt));
+        Duration::from_millis(backoff.min(self.max_backoff_ms))
+    }
+}
+
+impl fmt::Debug for LokaliseClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LokaliseClient")
+            .field("api_token", &"[REDACTED]")
+            .field("base_url", &self.base_url)
+            .field("retry_policy", &self.retry_policy)
+            .field("page_size", &self.page_size)
+            .field("pagination", &self.pagination)
+            .finish()
+    }
 }
 
 impl LokaliseClient {
-    pub fn new(api_token: String) -> Self {
+    pub fn new(api_token: String, config: &LokaliseConfig) -> Self {
         Self {
             api_token,
+            base_url: config.base_url.clone(),
             client: Client::new(),
+            retry_policy: config.retry.clone(),
+            page_size: config.page_size,
+            pagination: config.pagination,
         }
     }
 
     fn lokalise_url(&self, path: &str) -> Result<Url> {
         Ok(Url::parse(&format!(
-            "https://api.lokalise.com/api2/{}",
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
             path
         ))?)
     }
 
+    /// All projects the API token has access to.
     pub async fn projects(&self) -> Result<Vec<Project>> {
         #[derive(Deserialize)]
         struct Projects {
             projects: Vec<Project>,
         }
-        let resp = self.req::<Projects>(self.lokalise_url("projects")?).await?;
-        Ok(resp.projects)
+
+        // The most projects Lokalise returns per page.
+        let per_page = 100;
+        let mut page = 1;
+
+        let mut projects = vec![];
+
+        loop {
+            let mut url = self.lokalise_url("projects")?;
+            url.query_pairs_mut().append_pair("page", &page.to_string());
+            url.query_pairs_mut()
+                .append_pair("limit", &per_page.to_string());
+
+            let (resp, headers) = self.req::<Projects>(url).await?;
+            let pagination = PaginationHeaders::new(&headers);
+
+            let projects_len = resp.projects.len();
+            projects.extend(resp.projects);
+
+            let done = match pagination.page_count {
+                Some(page_count) => page >= page_count,
+                None => projects_len < per_page,
+            };
+            if done || projects_len == 0 {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(projects)
     }
 
+    /// Download all keys of `project`, with their translations.
+    ///
+    /// Fails if Lokalise reports a total that doesn't match the number of keys downloaded, which
+    /// it only does with offset pagination.
     pub async fn keys(&self, project: &Project) -> Result<Vec<Key>> {
         #[derive(Deserialize)]
         struct Keys {
             keys: Vec<Key>,
         }
 
-        let per_page = 5000;
         let mut page = 1;
+        let mut cursor = None::<String>;
+        let mut total_count = None;
 
         let mut keys = vec![];
 
@@ -47,62 +158,322 @@ impl LokaliseClient {
             let mut url = self.lokalise_url(&format!("projects/{}/keys", project.project_id))?;
             url.query_pairs_mut()
                 .append_pair("include_translations", "1");
-            url.query_pairs_mut().append_pair("page", &page.to_string());
             url.query_pairs_mut()
-                .append_pair("limit", &per_page.to_string());
+                .append_pair("limit", &self.page_size.to_string());
+            match self.pagination {
+                Pagination::Cursor => {
+                    url.query_pairs_mut().append_pair("pagination", "cursor");
+                    if let Some(cursor) = &cursor {
+                        url.query_pairs_mut().append_pair("cursor", cursor);
+                    }
+                }
+                Pagination::Offset => {
+                    url.query_pairs_mut().append_pair("page", &page.to_string());
+                }
+            }
 
-            let resp = self.req::<Keys>(url).await?;
+            let (resp, headers) = self.req::<Keys>(url).await?;
+            let pagination = PaginationHeaders::new(&headers);
 
-            page += 1;
+            total_count = pagination.total_count.or(total_count);
             let keys_len = resp.keys.len();
             keys.extend(resp.keys);
 
-            if keys_len < per_page {
+            let done = match self.pagination {
+                Pagination::Cursor => match pagination.next_cursor {
+                    Some(next_cursor) => {
+                        cursor = Some(next_cursor);
+                        false
+                    }
+                    None => true,
+                },
+                Pagination::Offset => match pagination.page_count {
+                    Some(page_count) => page >= page_count,
+                    None => keys_len < self.page_size as usize,
+                },
+            };
+            if done || keys_len == 0 {
                 break;
             }
+            page += 1;
+        }
+
+        // Lokalise leaves out `X-Pagination-Total-Count` with cursor pagination.
+        if let Some(total_count) = total_count {
+            if keys.len() != total_count {
+                return Err(Error::msg(format!(
+                    "Fetched {} keys from the {} project but Lokalise reported {}",
+                    keys.len(),
+                    project.name,
+                    total_count
+                )));
+            }
         }
 
         Ok(keys)
     }
 
-    async fn req<T>(&self, url: Url) -> Result<T>
+    /// Make a request, returning the headers of the response along with the body.
+    async fn req<T>(&self, url: Url) -> Result<(T, HeaderMap)>
     where
         T: DeserializeOwned,
     {
-        let json = self
-            .client
-            .get(url.clone())
-            .header("x-api-token", &self.api_token)
-            .send()
-            .await?
-            .json::<serde_json::Value>()
-            .await?;
-
-        match serde_json::from_value(json.clone()) {
-            Ok(out) => Ok(out),
-            Err(err) => {
-                eprintln!("Failed to decode response from Lokalise");
-                eprintln!("URL = {}", url);
-                eprintln!(
-                    "Response = {}",
-                    serde_json::to_string_pretty(&json).unwrap()
-                );
-                eprintln!("API token = {}", self.api_token);
-                eprintln!("-------------");
-
-                Err(err.into())
+        let mut attempt = 0;
+        let (status, headers, body) = loop {
+            let result = self
+                .client
+                .get(url.clone())
+                .header("x-api-token", &self.api_token)
+                .send()
+                .await;
+            let can_retry = attempt < self.retry_policy.max_retries;
+
+            let delay = match result {
+                Ok(resp) => {
+                    let status = resp.status();
+                    if can_retry
+                        && (status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+                    {
+                        retry_after(resp.headers())
+                            .unwrap_or_else(|| self.retry_policy.backoff(attempt))
+                    } else {
+                        let headers = resp.headers().clone();
+                        break (status, headers, resp.text().await?);
+                    }
+                }
+                Err(err) if can_retry && is_transient(&err) => self.retry_policy.backoff(attempt),
+                Err(err) => return Err(err.into()),
+            };
+
+            delay_for(delay).await;
+            attempt += 1;
+        };
+
+        if !status.is_success() {
+            return Err(self.status_error(&url, status, &body).into());
+        }
+
+        let out = serde_json::from_str(&body).map_err(|source| LokaliseError::Decode {
+            url: self.redact(url.as_str()),
+            status,
+            body: truncate(&self.redact(&body)),
+            source,
+        })?;
+        Ok((out, headers))
+    }
+
+    /// The error for an unsuccessful response, using the message from Lokalise's error envelope
+    /// if there is one.
+    fn status_error(&self, url: &Url, status: StatusCode, body: &str) -> LokaliseError {
+        #[derive(Deserialize)]
+        struct ErrorEnvelope {
+            error: ErrorBody,
+        }
+
+        #[derive(Deserialize)]
+        struct ErrorBody {
+            message: String,
+            code: Option<serde_json::Value>,
+        }
+
+        let message = match serde_json::from_str::<ErrorEnvelope>(body) {
+            Ok(ErrorEnvelope { error }) => match error.code {
+                // Lokalise sends numbers, but strings are cheap to support.
+                Some(code) => {
+                    let code = code.as_str().map_or_else(|| code.to_string(), String::from);
+                    self.redact(&format!("{} (code {})", error.message, code))
+                }
+                None => self.redact(&error.message),
+            },
+            Err(_) if body.trim().is_empty() => status
+                .canonical_reason()
+                .unwrap_or("no message")
+                .to_string(),
+            Err(_) => truncate(&self.redact(body)),
+        };
+        let url = self.redact(url.as_str());
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                LokaliseError::Unauthorized { url, message }
             }
+            StatusCode::NOT_FOUND => LokaliseError::NotFound { url, message },
+            StatusCode::TOO_MANY_REQUESTS => LokaliseError::RateLimited { url, message },
+            status if status.is_server_error() => LokaliseError::Server {
+                url,
+                status,
+                message,
+            },
+            status => LokaliseError::Request {
+                url,
+                status,
+                message,
+            },
+        }
+    }
+
+    /// Replace the API token with a placeholder, in case Lokalise echoes it back.
+    fn redact(&self, s: &str) -> String {
+        if self.api_token.is_empty() {
+            s.to_string()
+        } else {
+            s.replace(&self.api_token, "[REDACTED]")
+        }
+    }
+}
+
+/// Errors from talking to Lokalise.
+///
+/// Never contains the API token.
+#[derive(Debug)]
+pub enum LokaliseError {
+    /// The API token is invalid or lacks access.
+    Unauthorized { url: String, message: String },
+    /// The project or resource doesn't exist.
+    NotFound { url: String, message: String },
+    /// Too many requests have been made with the API token.
+    RateLimited { url: String, message: String },
+    /// Lokalise failed to handle the request.
+    Server {
+        url: String,
+        status: StatusCode,
+        message: String,
+    },
+    /// Lokalise rejected the request for some other reason.
+    Request {
+        url: String,
+        status: StatusCode,
+        message: String,
+    },
+    /// The response wasn't what we expected.
+    Decode {
+        url: String,
+        status: StatusCode,
+        body: String,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for LokaliseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LokaliseError::Unauthorized { url, message } => write!(
+                f,
+                "Lokalise rejected the API token for {}: {}. Check `--token` or `LOKALISE_API_TOKEN`",
+                url, message
+            ),
+            LokaliseError::NotFound { url, message } => {
+                write!(f, "Lokalise couldn't find {}: {}", url, message)
+            }
+            LokaliseError::RateLimited { url, message } => write!(
+                f,
+                "Lokalise rate limited the request to {}: {}. Try again in a moment",
+                url, message
+            ),
+            LokaliseError::Server {
+                url,
+                status,
+                message,
+            } => write!(
+                f,
+                "Lokalise failed to handle the request to {} ({}): {}",
+                url, status, message
+            ),
+            LokaliseError::Request {
+                url,
+                status,
+                message,
+            } => write!(
+                f,
+                "Lokalise rejected the request to {} ({}): {}",
+                url, status, message
+            ),
+            LokaliseError::Decode {
+                url, status, body, ..
+            } => write!(
+                f,
+                "Failed to decode response from Lokalise\nURL = {}\nStatus = {}\nResponse = {}",
+                url, status, body
+            ),
+        }
+    }
+}
+
+impl error::Error for LokaliseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            LokaliseError::Decode { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// The `X-Pagination-*` headers Lokalise includes with paginated responses.
+struct PaginationHeaders {
+    total_count: Option<usize>,
+    page_count: Option<u32>,
+    next_cursor: Option<String>,
+}
+
+impl PaginationHeaders {
+    fn new(headers: &HeaderMap) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+        };
+
+        Self {
+            total_count: header("x-pagination-total-count").and_then(|v| v.parse().ok()),
+            page_count: header("x-pagination-page-count").and_then(|v| v.parse().ok()),
+            next_cursor: header("x-pagination-next-cursor").map(String::from),
         }
     }
 }
 
-#[derive(Debug, Deserialize)]
+/// How long Lokalise asks us to wait before trying again.
+///
+/// That is either the standard `Retry-After` header in seconds, or Lokalise's
+/// `X-Rate-Limit-Reset` header with the Unix time the rate limit resets.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+
+    if let Some(seconds) = header("retry-after") {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let reset = header("x-rate-limit-reset")?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(Duration::from_secs(reset.saturating_sub(now).max(1)))
+}
+
+/// Whether a request failed in a way that might succeed if tried again, such as a timeout or
+/// lost connection.
+fn is_transient(err: &reqwest::Error) -> bool {
+    !err.is_builder() && !err.is_redirect()
+}
+
+fn truncate(body: &str) -> String {
+    match body.char_indices().nth(MAX_BODY_LEN) {
+        Some((idx, _)) => format!("{}... ({} more bytes)", &body[..idx], body.len() - idx),
+        None => body.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Project {
     pub project_id: String,
     pub name: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Key {
     pub key_id: i32,
     pub key_name: KeyName,
@@ -110,13 +481,13 @@ pub struct Key {
     pub is_plural: bool,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Translation {
     pub language_iso: String,
     pub translation: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct KeyName {
     pub ios: String,
     pub android: String,
@@ -125,7 +496,22 @@ pub struct KeyName {
 }
 
 impl KeyName {
-    pub fn all_same(&self) -> bool {
-        self.ios == self.android && self.android == self.web && self.web == self.other
+    pub fn for_platform(&self, platform: Platform) -> &str {
+        match platform {
+            Platform::Ios => &self.ios,
+            Platform::Android => &self.android,
+            Platform::Web => &self.web,
+            Platform::Other => &self.other,
+        }
     }
 }
+
+/// The platforms Lokalise lets keys have separate names for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Platform {
+    Ios,
+    Android,
+    Web,
+    Other,
+}
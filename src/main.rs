@@ -1,44 +1,117 @@
 mod code_gen;
+mod config;
+mod icu;
+mod kotlin;
 mod lokalise_client;
+mod names;
+mod plural_rules;
 mod scala_ast;
+mod snapshot;
+mod swift;
+mod typescript;
+mod validation;
 
 use anyhow::{Error, Result};
-use code_gen::generate_code;
+use code_gen::{generate_code, Output, GENERATED_HEADER};
+use config::{Config, Target};
 use crossterm::{
     cursor::{Hide, RestorePosition, SavePosition, Show},
     execute,
     style::Print,
     terminal::{Clear, ClearType},
 };
-use lokalise_client::{LokaliseClient, Project};
+use lokalise_client::{Key, LokaliseClient, Project};
+use similar::TextDiff;
+use snapshot::Snapshot;
 use std::ffi::OsStr;
 use std::{
     io::{self, Write},
     path::{Path, PathBuf},
     process::exit,
 };
-use structopt::StructOpt;
+use structopt::{clap::AppSettings, StructOpt};
 use tokio::stream::StreamExt;
 use tokio::{
     fs::{self, File},
-    io::{AsyncWrite, AsyncWriteExt},
+    io::AsyncWriteExt,
     runtime::Runtime,
     task,
     time::{delay_for, Duration},
 };
 
 /// Download translations from Lokalise and generate Scala code.
+///
+/// The options of `generate` can be given without the subcommand, but not together with
+/// another subcommand, which would ignore them.
 #[derive(Debug, StructOpt)]
+#[structopt(setting = AppSettings::ArgsNegateSubcommands)]
 struct Opt {
+    #[structopt(flatten)]
+    generate: GenerateOpt,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Generate code. This is what happens if no subcommand is given.
+    Generate(GenerateOpt),
+
+    /// Download keys from Lokalise and save them to a snapshot file.
+    ///
+    /// The snapshot can be used with `generate --from-snapshot` to generate code without
+    /// talking to Lokalise.
+    Fetch(FetchOpt),
+}
+
+#[derive(Debug, StructOpt)]
+struct GenerateOpt {
     /// Print the code to stdout rather than a file.
     #[structopt(long = "stdout", short = "s")]
     print_to_stdout: bool,
 
+    /// Don't write anything, instead fail if the generated file is out of date.
+    ///
+    /// Prints a diff between the file on disk and what would have been generated.
+    #[structopt(long = "check", conflicts_with = "print-to-stdout")]
+    check: bool,
+
+    /// The language to generate code for. Either "scala", "typescript", "kotlin" or "swift".
+    #[structopt(long = "target", default_value = "scala")]
+    target: Target,
+
+    /// Generate code from a snapshot made with `fetch` rather than downloading from Lokalise.
+    #[structopt(long = "from-snapshot", parse(from_os_str))]
+    from_snapshot: Option<PathBuf>,
+
+    #[structopt(flatten)]
+    shared: SharedOpt,
+}
+
+#[derive(Debug, StructOpt)]
+struct FetchOpt {
+    /// Path to write the snapshot to.
+    #[structopt(parse(from_os_str))]
+    snapshot: PathBuf,
+
+    #[structopt(flatten)]
+    shared: SharedOpt,
+}
+
+#[derive(Debug, StructOpt)]
+struct SharedOpt {
     /// Your Lokalise API token.
     ///
     /// If not set it'll use the `LOKALISE_API_TOKEN` environment variable.
     #[structopt(long = "token", short = "t")]
     api_token: Option<String>,
+
+    /// Path to the config file.
+    ///
+    /// If not set it'll use `i18n-code-gen.toml` in the root of the backend project, if it exists.
+    #[structopt(long = "config", short = "c", parse(from_os_str))]
+    config: Option<PathBuf>,
 }
 
 fn main() -> Result<()> {
@@ -55,7 +128,8 @@ fn main() -> Result<()> {
         Ok(ok) => match ok {
             Ok(()) => {}
             Err(err) => {
-                eprintln!("{}", err);
+                // Debug formatting includes the causes of the error.
+                eprintln!("{:?}", err);
                 exit(1);
             }
         },
@@ -76,50 +150,212 @@ async fn async_main(opt: Opt) -> Result<()> {
 
     show_spinner();
 
+    match opt.command {
+        Some(Command::Generate(opt)) => generate(opt).await,
+        Some(Command::Fetch(opt)) => fetch(opt).await,
+        None => generate(opt.generate).await,
+    }
+}
+
+async fn generate(opt: GenerateOpt) -> Result<()> {
+    let (config, root) = load_config(&opt.shared).await?;
+
+    let project_and_keys = if let Some(path) = &opt.from_snapshot {
+        let json = fs::read_to_string(path)
+            .await
+            .map_err(|err| Error::msg(format!("Failed to read {}: {}", path.display(), err)))?;
+        Snapshot::from_json(&json)?.into_projects()
+    } else {
+        fetch_keys(&opt.shared, &config).await?
+    };
+
+    let output = generate_code(project_and_keys, &config, opt.target)?;
+    execute!(io::stderr(), Clear(ClearType::CurrentLine))?;
+
     if opt.print_to_stdout {
-        let stdout = tokio::io::stdout();
-        gen_code_and_write_to(opt, stdout).await?;
+        // Each file is preceded by a comment with its path when there are several.
+        let code = match output {
+            Output::File(code) => code,
+            Output::Files(files) => files
+                .iter()
+                .map(|(path, code)| format!("// {}\n{}", path.display(), code))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
+
+        let mut stdout = tokio::io::stdout();
+        stdout.write_all(code.as_bytes()).await?;
+        stdout.flush().await?;
+        return Ok(());
+    }
+
+    let root = root?;
+    let files = match output {
+        Output::File(code) => vec![(root.join(config.output_path(opt.target)?), code)],
+        Output::Files(files) => files
+            .into_iter()
+            .map(|(path, code)| (root.join(path), code))
+            .collect(),
+    };
+
+    let stale = match &config.split {
+        Some(split) if opt.target == Target::Scala => {
+            stale_files(&root.join(&split.dir), &files).await?
+        }
+        _ => Vec::new(),
+    };
+
+    if opt.check {
+        check_up_to_date(&files, &stale).await?;
     } else {
-        let path = path_to_write_to()
-            .await?
-            .join("shared/src/main/scala/dk/undo/i18n/I18n.scala");
-        let file = File::create(path).await?;
-        gen_code_and_write_to(opt, file).await?;
+        for (path, code) in &files {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).await?;
+            }
+            let mut file = File::create(path).await?;
+            file.write_all(code.as_bytes()).await?;
+            file.flush().await?;
+        }
+        for path in &stale {
+            fs::remove_file(path).await?;
+        }
     }
 
     Ok(())
 }
 
-async fn gen_code_and_write_to<W>(opt: Opt, mut out: W) -> Result<()>
-where
-    W: AsyncWrite + Unpin,
-{
-    let api_token = if let Some(api_token) = opt.api_token {
+/// Generated files directly in `dir` that aren't among `files`, like the file of a project
+/// that's no longer in the config.
+async fn stale_files(dir: &Path, files: &[(PathBuf, String)]) -> Result<Vec<PathBuf>> {
+    let mut entries = match fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut stale = Vec::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type().await?.is_file()
+            && !files.iter().any(|(file, _)| *file == path)
+            && fs::read(&path)
+                .await?
+                .starts_with(GENERATED_HEADER.as_bytes())
+        {
+            stale.push(path);
+        }
+    }
+
+    stale.sort();
+    Ok(stale)
+}
+
+async fn fetch(opt: FetchOpt) -> Result<()> {
+    let (config, _root) = load_config(&opt.shared).await?;
+
+    let project_and_keys = fetch_keys(&opt.shared, &config).await?;
+    let json = Snapshot::new(project_and_keys).to_json()?;
+    execute!(io::stderr(), Clear(ClearType::CurrentLine))?;
+
+    let mut file = File::create(&opt.snapshot).await?;
+    file.write_all(json.as_bytes()).await?;
+    file.flush().await?;
+
+    Ok(())
+}
+
+/// Load the config along with the directory paths in it are relative to.
+///
+/// Not finding that directory is only an error if something has to be written there, so that
+/// is returned rather than failed on.
+async fn load_config(opt: &SharedOpt) -> Result<(Config, Result<PathBuf>)> {
+    let root = match &opt.config {
+        Some(path) => Ok(path
+            .parent()
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("."))),
+        None => path_to_write_to().await,
+    };
+
+    let config = match (&opt.config, &root) {
+        (Some(path), _) => Config::load(path).await?,
+        (None, Ok(root)) if root.join(config::FILE_NAME).exists() => {
+            Config::load(&root.join(config::FILE_NAME)).await?
+        }
+        _ => Config::default(),
+    };
+
+    Ok((config, root))
+}
+
+async fn fetch_keys(opt: &SharedOpt, config: &Config) -> Result<Vec<(Project, Vec<Key>)>> {
+    let api_token = if let Some(api_token) = opt.api_token.clone() {
         api_token
     } else {
         std::env::var("LOKALISE_API_TOKEN").expect("LOKALISE_API_TOKEN is not set")
     };
 
-    let client = LokaliseClient::new(api_token);
+    let client = LokaliseClient::new(api_token, &config.lokalise);
 
     // Lokalise's API doesn't support concurrent requests using the same API token...
-    // So don't bother making these requests in parallel.
+    // So don't bother making these requests in parallel. Requests rejected because someone
+    // else is using the token at the same time are retried by the client.
 
-    let projects = vec!["Undo", "Car"];
+    let projects = client.projects().await?;
 
     let mut project_and_keys = Vec::new();
-    for name in projects {
-        let project = find_project(name, &client).await?;
-        let keys = client.keys(&project).await?;
-        project_and_keys.push((project, keys));
+    for project in find_projects(&config.projects, &projects)? {
+        let keys = client.keys(project).await?;
+        project_and_keys.push((project.clone(), keys));
     }
 
-    let code = generate_code(project_and_keys)?;
-    execute!(io::stderr(), Clear(ClearType::CurrentLine))?;
+    Ok(project_and_keys)
+}
+
+/// Print a diff for each of the files that differs from what's on disk, and for each `stale`
+/// file that would be deleted, and fail if there are any.
+async fn check_up_to_date(files: &[(PathBuf, String)], stale: &[PathBuf]) -> Result<()> {
+    let mut out_of_date = Vec::new();
+
+    let files = files
+        .iter()
+        .map(|(path, code)| (path, code.as_str()))
+        .chain(stale.iter().map(|path| (path, "")));
+    for (path, code) in files {
+        let current = match fs::read_to_string(path).await {
+            Ok(current) => current,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        if current == code {
+            continue;
+        }
 
-    out.write_all(code.as_bytes()).await?;
+        let path_name = path.display().to_string();
+        let diff = TextDiff::from_lines(current.as_str(), code)
+            .unified_diff()
+            .header(&path_name, &path_name)
+            .to_string();
+        let mut stdout = tokio::io::stdout();
+        stdout.write_all(diff.as_bytes()).await?;
+        stdout.flush().await?;
 
-    Result::<_>::Ok(())
+        out_of_date.push(path_name);
+    }
+
+    match out_of_date.as_slice() {
+        [] => Ok(()),
+        [path_name] => Err(Error::msg(format!(
+            "{} is out of date. Run i18n-code-gen to regenerate it.",
+            path_name
+        ))),
+        path_names => Err(Error::msg(format!(
+            "{} are out of date. Run i18n-code-gen to regenerate them.",
+            path_names.join(", ")
+        ))),
+    }
 }
 
 async fn path_to_write_to() -> Result<PathBuf> {
@@ -158,14 +394,94 @@ async fn is_root_of_backend(path: &Path) -> Result<bool> {
     Ok(contains_git && contains_build_sbt)
 }
 
-async fn find_project(name: &str, client: &LokaliseClient) -> Result<Project> {
-    let project = client
-        .projects()
-        .await?
-        .into_iter()
-        .find(|project| project.name == name)
-        .ok_or_else(|| Error::msg(format!("Couldn't find {} project", name)))?;
-    Ok(project)
+/// Find the project for each of the names or IDs, failing if two of them are the same project.
+fn find_projects<'a>(names_or_ids: &[String], projects: &'a [Project]) -> Result<Vec<&'a Project>> {
+    let mut found = Vec::<(&str, &Project)>::new();
+
+    for name_or_id in names_or_ids {
+        let project = find_project(name_or_id, projects)?;
+        let duplicate = found
+            .iter()
+            .find(|(_, other)| other.project_id == project.project_id);
+        if let Some((other_name_or_id, _)) = duplicate {
+            let listed = if other_name_or_id == name_or_id {
+                format!("{:?} is listed twice", name_or_id)
+            } else {
+                format!(
+                    "{:?} and {:?} are the same project",
+                    other_name_or_id, name_or_id
+                )
+            };
+            return Err(Error::msg(format!(
+                "{} in the config's `projects`: {} ({})",
+                listed, project.name, project.project_id
+            )));
+        }
+        found.push((name_or_id, project));
+    }
+
+    Ok(found.into_iter().map(|(_, project)| project).collect())
+}
+
+/// Find the project with the given name or ID.
+fn find_project<'a>(name_or_id: &str, projects: &'a [Project]) -> Result<&'a Project> {
+    let project = projects
+        .iter()
+        .find(|project| project.project_id == name_or_id)
+        .or_else(|| projects.iter().find(|project| project.name == name_or_id));
+
+    if let Some(project) = project {
+        return Ok(project);
+    }
+
+    let mut similar = projects
+        .iter()
+        .map(|project| {
+            let distance = edit_distance(&project.name.to_lowercase(), &name_or_id.to_lowercase());
+            (distance, &project.name)
+        })
+        .filter(|(distance, name)| {
+            *distance <= 2.max(name_or_id.chars().count() / 3)
+                || name.to_lowercase().contains(&name_or_id.to_lowercase())
+        })
+        .collect::<Vec<_>>();
+    similar.sort();
+
+    let mut message = format!(
+        "Couldn't find a Lokalise project with the name or ID {:?}",
+        name_or_id
+    );
+    if !similar.is_empty() {
+        message.push_str(". Did you mean ");
+        message.push_str(
+            &similar
+                .iter()
+                .take(5)
+                .map(|(_, name)| format!("{:?}", name))
+                .collect::<Vec<_>>()
+                .join(" or "),
+        );
+        message.push('?');
+    }
+
+    Err(Error::msg(message))
+}
+
+/// The Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
 }
 
 #[allow(unreachable_code)]
@@ -204,3 +520,74 @@ fn show_spinner() {
         Result::<_>::Ok(())
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(project_id: &str, name: &str) -> Project {
+        Project {
+            project_id: project_id.to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn edit_distance_counts_insertions_deletions_and_substitutions() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("undo", "undo"), 0);
+        assert_eq!(edit_distance("", "car"), 3);
+        assert_eq!(edit_distance("car", ""), 3);
+        assert_eq!(edit_distance("car", "cars"), 1);
+        assert_eq!(edit_distance("cars", "car"), 1);
+        assert_eq!(edit_distance("car", "bar"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("ærø", "æro"), 1);
+    }
+
+    #[test]
+    fn finds_projects_by_name_or_id() {
+        let projects = vec![project("1111.aaaa", "Undo"), project("2222.bbbb", "Car")];
+
+        let found = find_projects(&names(&["2222.bbbb", "Undo"]), &projects).unwrap();
+
+        assert_eq!(
+            found.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["Car", "Undo"]
+        );
+    }
+
+    #[test]
+    fn suggests_similar_project_names() {
+        let projects = vec![project("1111.aaaa", "Undo"), project("2222.bbbb", "Car")];
+
+        let err = find_projects(&names(&["Cars"]), &projects).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Couldn't find a Lokalise project with the name or ID \"Cars\". Did you mean \"Car\"?"
+        );
+    }
+
+    #[test]
+    fn rejects_projects_listed_twice() {
+        let projects = vec![project("1111.aaaa", "Undo"), project("2222.bbbb", "Car")];
+
+        let err = find_projects(&names(&["Undo", "Car", "Undo"]), &projects).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"Undo\" is listed twice in the config's `projects`: Undo (1111.aaaa)"
+        );
+
+        let err = find_projects(&names(&["Undo", "1111.aaaa"]), &projects).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"Undo\" and \"1111.aaaa\" are the same project in the config's `projects`: \
+             Undo (1111.aaaa)"
+        );
+    }
+}
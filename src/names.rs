@@ -0,0 +1,120 @@
+use crate::config::NameCollisions;
+use anyhow::Error;
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+};
+
+/// Names from Lokalise that become the same identifier in the generated code.
+#[derive(Debug)]
+pub struct Collision {
+    /// What the identifier names, such as "Undo: method".
+    pub what: String,
+    pub ident: String,
+    /// The names as written in Lokalise, sorted with one spelled like the identifier first.
+    pub originals: Vec<String>,
+    /// Whether the identifier is taken by the generated code itself.
+    pub reserved: bool,
+}
+
+impl fmt::Display for Collision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let originals = self
+            .originals
+            .iter()
+            .map(|original| format!("{:?}", original))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "{} `{}` from {}", self.what, self.ident, originals)?;
+        if self.reserved {
+            write!(f, ", which the generated code uses itself")?;
+        }
+        Ok(())
+    }
+}
+
+/// Make an identifier for each of the distinct `originals` using `to_ident`.
+///
+/// Originals that become the same identifier, or one of `reserved`, collide. They're returned
+/// as errors unless `policy` is to disambiguate, in which case all but one of them get the
+/// lowest number that makes them unique appended. The one keeping the identifier is the one
+/// already spelled like it or otherwise the first in sort order, so the result doesn't depend
+/// on the order of `originals`.
+pub fn unique_idents(
+    originals: &[&str],
+    to_ident: impl Fn(&str) -> String,
+    reserved: &[&str],
+    policy: NameCollisions,
+    what: &str,
+) -> Result<Vec<String>, Vec<Collision>> {
+    let mut idents = originals
+        .iter()
+        .map(|original| to_ident(original))
+        .collect::<Vec<_>>();
+
+    let mut groups = BTreeMap::<String, Vec<usize>>::new();
+    for (idx, ident) in idents.iter().enumerate() {
+        groups.entry(ident.clone()).or_default().push(idx);
+    }
+
+    let mut taken = idents
+        .iter()
+        .map(String::as_str)
+        .chain(reserved.iter().copied())
+        .map(String::from)
+        .collect::<HashSet<_>>();
+    let mut collisions = Vec::new();
+
+    for (ident, mut group) in groups {
+        let is_reserved = reserved.contains(&ident.as_str());
+        if group.len() < 2 && !is_reserved {
+            continue;
+        }
+
+        group.sort_by_key(|idx| (originals[*idx] != ident, originals[*idx]));
+
+        if policy == NameCollisions::Error {
+            collisions.push(Collision {
+                what: what.to_string(),
+                ident,
+                originals: group
+                    .iter()
+                    .map(|idx| originals[*idx].to_string())
+                    .collect(),
+                reserved: is_reserved,
+            });
+            continue;
+        }
+
+        let keep = if is_reserved { 0 } else { 1 };
+        for idx in group.into_iter().skip(keep) {
+            let unique = (2..)
+                .map(|number| format!("{}{}", ident, number))
+                .find(|candidate| !taken.contains(candidate))
+                .unwrap();
+            taken.insert(unique.clone());
+            idents[idx] = unique;
+        }
+    }
+
+    if collisions.is_empty() {
+        Ok(idents)
+    } else {
+        Err(collisions)
+    }
+}
+
+/// The error reported for collisions when they aren't disambiguated.
+pub fn collision_error(collisions: &[Collision]) -> Error {
+    let report = collisions
+        .iter()
+        .map(|collision| format!("  {}", collision))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Error::msg(format!(
+        "Names collide after converting them to identifiers:\n{}\n\
+         Rename them in Lokalise, or set `name_collisions = \"disambiguate\"` in the \
+         `[validation]` config section to number them.",
+        report
+    ))
+}
@@ -0,0 +1,455 @@
+use anyhow::{Error, Result};
+
+/// The CLDR plural categories.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    pub const ALL: [PluralCategory; 6] = [
+        PluralCategory::Zero,
+        PluralCategory::One,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+        PluralCategory::Other,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "Zero",
+            PluralCategory::One => "One",
+            PluralCategory::Two => "Two",
+            PluralCategory::Few => "Few",
+            PluralCategory::Many => "Many",
+            PluralCategory::Other => "Other",
+        }
+    }
+}
+
+type Rules = &'static [(PluralCategory, &'static str)];
+
+/// Integer plural rules from CLDR, by language.
+///
+/// Only rules for integers are included since counts are always `Int`s. Categories are checked
+/// in order and everything not matching a rule is `Other`.
+///
+/// See <https://unicode-org.github.io/cldr-staging/charts/latest/supplemental/language_plural_rules.html>
+const RULES: &[(&[&str], Rules)] = &[
+    (
+        &[
+            "bg", "ca", "da", "de", "el", "en", "es", "et", "eu", "fi", "gl", "hu", "it", "ka",
+            "kk", "nb", "nl", "nn", "no", "pt_pt", "sv", "tr",
+        ],
+        &[(PluralCategory::One, "n = 1")],
+    ),
+    (&["fr", "pt"], &[(PluralCategory::One, "n = 0,1")]),
+    (
+        &["is"],
+        &[(PluralCategory::One, "n % 10 = 1 and n % 100 != 11")],
+    ),
+    (
+        &["pl"],
+        &[
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Few, "n % 10 = 2..4 and n % 100 != 12..14"),
+            (
+                PluralCategory::Many,
+                "n % 10 = 0..1 or n % 10 = 5..9 or n % 100 = 12..14",
+            ),
+        ],
+    ),
+    (
+        &["be", "ru", "uk"],
+        &[
+            (PluralCategory::One, "n % 10 = 1 and n % 100 != 11"),
+            (PluralCategory::Few, "n % 10 = 2..4 and n % 100 != 12..14"),
+            (
+                PluralCategory::Many,
+                "n % 10 = 0 or n % 10 = 5..9 or n % 100 = 11..14",
+            ),
+        ],
+    ),
+    (
+        &["bs", "hr", "sr"],
+        &[
+            (PluralCategory::One, "n % 10 = 1 and n % 100 != 11"),
+            (PluralCategory::Few, "n % 10 = 2..4 and n % 100 != 12..14"),
+        ],
+    ),
+    (
+        &["cs", "sk"],
+        &[
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Few, "n = 2..4"),
+        ],
+    ),
+    (
+        &["lt"],
+        &[
+            (PluralCategory::One, "n % 10 = 1 and n % 100 != 11..19"),
+            (PluralCategory::Few, "n % 10 = 2..9 and n % 100 != 11..19"),
+        ],
+    ),
+    (
+        &["lv"],
+        &[
+            (PluralCategory::Zero, "n % 10 = 0 or n % 100 = 11..19"),
+            (PluralCategory::One, "n % 10 = 1 and n % 100 != 11"),
+        ],
+    ),
+    (
+        &["ro"],
+        &[
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Few, "n = 0 or n != 1 and n % 100 = 1..19"),
+        ],
+    ),
+    (
+        &["sl"],
+        &[
+            (PluralCategory::One, "n % 100 = 1"),
+            (PluralCategory::Two, "n % 100 = 2"),
+            (PluralCategory::Few, "n % 100 = 3..4"),
+        ],
+    ),
+    (
+        &["he"],
+        &[
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Two, "n = 2"),
+        ],
+    ),
+    (
+        &["ga"],
+        &[
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Two, "n = 2"),
+            (PluralCategory::Few, "n = 3..6"),
+            (PluralCategory::Many, "n = 7..10"),
+        ],
+    ),
+    (
+        &["cy"],
+        &[
+            (PluralCategory::Zero, "n = 0"),
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Two, "n = 2"),
+            (PluralCategory::Few, "n = 3"),
+            (PluralCategory::Many, "n = 6"),
+        ],
+    ),
+    (
+        &["ar"],
+        &[
+            (PluralCategory::Zero, "n = 0"),
+            (PluralCategory::One, "n = 1"),
+            (PluralCategory::Two, "n = 2"),
+            (PluralCategory::Few, "n % 100 = 3..10"),
+            (PluralCategory::Many, "n % 100 = 11..99"),
+        ],
+    ),
+    (&["id", "ja", "ko", "lo", "ms", "my", "th", "vi", "zh"], &[]),
+];
+
+/// Languages without known rules get the English ones, with a warning.
+const DEFAULT_RULES: Rules = &[(PluralCategory::One, "n = 1")];
+
+/// The plural rules for a Lokalise language code such as `da`, `en_US` or `pt_PT`.
+///
+/// Each category is paired with the condition, in terms of `n`, that selects it.
+pub fn rules_for(language_iso: &str) -> Result<Vec<(PluralCategory, Condition)>> {
+    let full = language_iso.to_lowercase().replace('-', "_");
+    let language = full.split('_').next().unwrap_or("");
+
+    let rules = RULES
+        .iter()
+        .find(|(languages, _)| languages.contains(&full.as_str()))
+        .or_else(|| {
+            RULES
+                .iter()
+                .find(|(languages, _)| languages.contains(&language))
+        })
+        .map(|(_, rules)| *rules)
+        .unwrap_or_else(|| {
+            eprintln!(
+                "warning: No plural rules are known for {:?}, so the English ones are used",
+                language_iso
+            );
+            DEFAULT_RULES
+        });
+
+    rules
+        .iter()
+        .map(|(category, rule)| Ok((*category, Condition::parse(rule)?)))
+        .collect()
+}
+
+/// A condition in the CLDR plural rule syntax, restricted to the integer operand `n`.
+///
+/// Disjunction of conjunctions, like the syntax itself.
+#[derive(Debug)]
+pub struct Condition {
+    any_of: Vec<Vec<Relation>>,
+}
+
+#[derive(Debug)]
+struct Relation {
+    modulo: Option<u32>,
+    equal: bool,
+    ranges: Vec<(u32, u32)>,
+}
+
+impl Condition {
+    fn parse(rule: &str) -> Result<Self> {
+        let any_of = rule
+            .split(" or ")
+            .map(|conjunction| {
+                conjunction
+                    .split(" and ")
+                    .map(|relation| Relation::parse(relation.trim()))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { any_of })
+    }
+
+    /// Render the condition as a C-style boolean expression with `var` as the operand.
+    ///
+    /// The rules are defined on the absolute value of the count, so `var` must not be negative.
+    pub fn to_code(&self, var: &str) -> String {
+        let code = self
+            .any_of
+            .iter()
+            .map(|conjunction| {
+                conjunction
+                    .iter()
+                    .map(|relation| relation.to_code(var))
+                    .collect::<Vec<_>>()
+                    .join(" && ")
+            })
+            .collect::<Vec<_>>();
+
+        if code.len() == 1 {
+            code.into_iter().next().unwrap()
+        } else {
+            code.into_iter()
+                .map(|conjunction| format!("({})", conjunction))
+                .collect::<Vec<_>>()
+                .join(" || ")
+        }
+    }
+}
+
+impl Relation {
+    fn parse(relation: &str) -> Result<Self> {
+        let invalid = || Error::msg(format!("Invalid plural rule {:?}", relation));
+
+        let (operand, equal, ranges) = if let Some(idx) = relation.find("!=") {
+            (&relation[..idx], false, &relation[idx + 2..])
+        } else if let Some(idx) = relation.find('=') {
+            (&relation[..idx], true, &relation[idx + 1..])
+        } else {
+            return Err(invalid());
+        };
+
+        let modulo = match operand.trim().split('%').map(str::trim).collect::<Vec<_>>()[..] {
+            ["n"] => None,
+            ["n", modulo] => Some(modulo.parse().map_err(|_| invalid())?),
+            _ => return Err(invalid()),
+        };
+
+        let ranges = ranges
+            .split(',')
+            .map(|range| {
+                let mut bounds = range.trim().splitn(2, "..");
+                let start = bounds.next().ok_or_else(invalid)?;
+                let start = start.trim().parse::<u32>().map_err(|_| invalid())?;
+                let end = match bounds.next() {
+                    Some(end) => end.trim().parse::<u32>().map_err(|_| invalid())?,
+                    None => start,
+                };
+                Ok((start, end))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            modulo,
+            equal,
+            ranges,
+        })
+    }
+
+    fn to_code(&self, var: &str) -> String {
+        let operand = match self.modulo {
+            Some(modulo) => format!("{} % {}", var, modulo),
+            None => var.to_string(),
+        };
+
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|&(start, end)| match (start == end, self.equal) {
+                (true, true) => format!("{} == {}", operand, start),
+                (true, false) => format!("{} != {}", operand, start),
+                (false, true) => format!("{} >= {} && {} <= {}", operand, start, operand, end),
+                (false, false) => format!("({} < {} || {} > {})", operand, start, operand, end),
+            })
+            .collect::<Vec<_>>();
+
+        if ranges.len() == 1 {
+            ranges.into_iter().next().unwrap()
+        } else if self.equal {
+            format!("({})", ranges.join(" || "))
+        } else {
+            ranges.join(" && ")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PluralCategory::*;
+
+    impl Relation {
+        fn matches(&self, n: u32) -> bool {
+            let operand = self.modulo.map_or(n, |modulo| n % modulo);
+            let in_range = self
+                .ranges
+                .iter()
+                .any(|&(start, end)| start <= operand && operand <= end);
+            in_range == self.equal
+        }
+    }
+
+    fn category(language_iso: &str, n: u32) -> PluralCategory {
+        rules_for(language_iso)
+            .unwrap()
+            .into_iter()
+            .find(|(_, condition)| {
+                condition
+                    .any_of
+                    .iter()
+                    .any(|conjunction| conjunction.iter().all(|relation| relation.matches(n)))
+            })
+            .map_or(Other, |(category, _)| category)
+    }
+
+    fn categories(language_iso: &str, counts: &[u32]) -> Vec<PluralCategory> {
+        counts.iter().map(|&n| category(language_iso, n)).collect()
+    }
+
+    #[test]
+    fn polish() {
+        assert_eq!(
+            categories("pl", &[0, 1, 2, 4, 5, 12, 14, 21, 22, 25, 102, 112]),
+            vec![Many, One, Few, Few, Many, Many, Many, Many, Few, Many, Few, Many]
+        );
+    }
+
+    #[test]
+    fn russian() {
+        assert_eq!(
+            categories("ru", &[0, 1, 2, 5, 11, 12, 21, 22, 25, 111, 101]),
+            vec![Many, One, Few, Many, Many, Many, One, Few, Many, Many, One]
+        );
+    }
+
+    #[test]
+    fn arabic() {
+        assert_eq!(
+            categories("ar", &[0, 1, 2, 3, 10, 11, 99, 100, 102, 103, 111]),
+            vec![Zero, One, Two, Few, Few, Many, Many, Other, Other, Few, Many]
+        );
+    }
+
+    #[test]
+    fn romanian() {
+        assert_eq!(
+            categories("ro", &[0, 1, 2, 19, 20, 100, 101, 119, 120]),
+            vec![Few, One, Few, Few, Other, Other, Few, Few, Other]
+        );
+    }
+
+    #[test]
+    fn czech() {
+        assert_eq!(
+            categories("cs", &[0, 1, 2, 4, 5, 22]),
+            vec![Other, One, Few, Few, Other, Other]
+        );
+    }
+
+    #[test]
+    fn region_specific_rules_take_precedence() {
+        assert_eq!(categories("pt", &[0, 1, 2]), vec![One, One, Other]);
+        assert_eq!(categories("pt_PT", &[0, 1, 2]), vec![Other, One, Other]);
+        assert_eq!(categories("en-US", &[0, 1, 2]), vec![Other, One, Other]);
+    }
+
+    #[test]
+    fn unknown_languages_use_english_rules() {
+        assert_eq!(categories("xx", &[0, 1, 2]), vec![Other, One, Other]);
+    }
+
+    #[test]
+    fn parses_ranges_and_lists() {
+        let relation = Relation::parse("n % 100 = 3..10, 15").unwrap();
+        assert_eq!(relation.modulo, Some(100));
+        assert!(relation.equal);
+        assert_eq!(relation.ranges, vec![(3, 10), (15, 15)]);
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        assert!(Condition::parse("n % x = 1").is_err());
+        assert!(Condition::parse("i = 1").is_err());
+        assert!(Condition::parse("n = 1..").is_err());
+        assert!(Condition::parse("n < 1").is_err());
+    }
+
+    #[test]
+    fn renders_equality() {
+        let condition = Condition::parse("n = 1").unwrap();
+        assert_eq!(condition.to_code("n"), "n == 1");
+
+        let condition = Condition::parse("n % 10 = 2..4").unwrap();
+        assert_eq!(
+            condition.to_code("count"),
+            "count % 10 >= 2 && count % 10 <= 4"
+        );
+
+        let condition = Condition::parse("n = 0,1").unwrap();
+        assert_eq!(condition.to_code("n"), "(n == 0 || n == 1)");
+    }
+
+    #[test]
+    fn renders_inequality() {
+        let condition = Condition::parse("n % 100 != 11").unwrap();
+        assert_eq!(condition.to_code("n"), "n % 100 != 11");
+
+        let condition = Condition::parse("n % 100 != 12..14").unwrap();
+        assert_eq!(condition.to_code("n"), "(n % 100 < 12 || n % 100 > 14)");
+
+        let condition = Condition::parse("n != 1,3").unwrap();
+        assert_eq!(condition.to_code("n"), "n != 1 && n != 3");
+    }
+
+    #[test]
+    fn renders_conjunctions_and_disjunctions() {
+        let condition = Condition::parse("n % 10 = 1 and n % 100 != 11").unwrap();
+        assert_eq!(condition.to_code("n"), "n % 10 == 1 && n % 100 != 11");
+
+        let condition = Condition::parse("n % 10 = 0 or n % 100 = 11..14").unwrap();
+        assert_eq!(
+            condition.to_code("n"),
+            "(n % 10 == 0) || (n % 100 >= 11 && n % 100 <= 14)"
+        );
+    }
+}
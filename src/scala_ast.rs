@@ -1,6 +1,7 @@
 use itertools::{Itertools, Position};
 use regex::Regex;
-use std::{cell::RefCell, fmt::Write};
+use serde::Deserialize;
+use std::{cell::RefCell, fmt, fmt::Write};
 
 macro_rules! write {
     (
@@ -60,17 +61,28 @@ macro_rules! writeln {
     };
 }
 
-pub fn to_code<T: ToCode>(ast: T) -> String {
+pub fn to_code<T: ToCode>(ast: T, dialect: Dialect) -> String {
     let mut out = String::new();
-    ast.to_code(&mut out, 0);
+    ast.to_code(&mut out, 0, dialect);
     out
 }
 
+/// The version of Scala the code is written for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Dialect {
+    #[default]
+    #[serde(rename = "2.13")]
+    Scala2,
+    /// Uses `enum`s and `using` clauses.
+    #[serde(rename = "3")]
+    Scala3,
+}
+
 pub trait ToCode {
-    fn to_code(&self, out: &mut String, indent: usize);
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect);
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Ident {
     pub name: String,
 }
@@ -86,96 +98,249 @@ impl Ident {
     }
 }
 
-impl ToCode for Ident {
-    fn to_code(&self, out: &mut String, indent: usize) {
+impl Ident {
+    /// Whether the name can be written as is in both Scala 2 and Scala 3.
+    ///
+    /// That's an alphanumeric identifier that isn't a keyword in either. Identifiers made of or
+    /// ending in operator characters are valid too, but are quoted anyway since they're easily
+    /// mistaken for something else, like `x_:` in `x_: String`. Quoting is always allowed.
+    fn is_plain(&self) -> bool {
         lazy_static::lazy_static! {
-            static ref LEADING_DIGIT: Regex = Regex::new(
-                r#"^\d"#
+            // The `letter`s and `digit`s of the Scala grammar. `$` is only meant for the
+            // compiler but allowed.
+            static ref ALPHANUMERIC_ID: Regex = Regex::new(
+                r#"^[\p{Lu}\p{Ll}\p{Lo}\p{Lt}\p{Nl}_$][\p{Lu}\p{Ll}\p{Lo}\p{Lt}\p{Nl}_$0-9]*$"#
             ).unwrap();
         }
 
-        if is_keyword(&self.name) || LEADING_DIGIT.is_match(&self.name) {
-            write!(out, indent, "`{}`", self.name)
+        ALPHANUMERIC_ID.is_match(&self.name) && !self.name.ends_with('_') && !is_keyword(&self.name)
+    }
+
+    /// The name in backticks, with the characters that can't be quoted replaced by `_`.
+    fn quoted(&self) -> String {
+        let name = self
+            .name
+            .chars()
+            .map(|c| if c == '`' || c.is_control() { '_' } else { c })
+            .collect::<String>();
+
+        if name.is_empty() {
+            "`_`".to_string()
         } else {
-            write!(out, indent, "{}", self.name)
+            format!("`{}`", name)
         }
     }
 }
 
-#[derive(Debug)]
+/// Identifiers are written the same way in every dialect, so they can be formatted directly.
+impl fmt::Display for Ident {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_plain() {
+            f.write_str(&self.name)
+        } else {
+            f.write_str(&self.quoted())
+        }
+    }
+}
+
+impl ToCode for Ident {
+    fn to_code(&self, out: &mut String, indent: usize, _dialect: Dialect) {
+        write!(out, indent, "{}", self)
+    }
+}
+
+/// A possibly qualified name, such as `java.util.Locale.forLanguageTag`.
+#[derive(Debug, Clone)]
+pub struct Path {
+    pub segments: Vec<Ident>,
+}
+
+impl Path {
+    pub fn new(path: &str) -> Self {
+        Self {
+            segments: path.split('.').map(Ident::new).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.segments.iter().join("."))
+    }
+}
+
+impl ToCode for Path {
+    fn to_code(&self, out: &mut String, indent: usize, _dialect: Dialect) {
+        write!(out, indent, "{}", self)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum Expr {
     Match {
         expr: Box<Expr>,
         clauses: Vec<MatchClause>,
     },
+    /// A triple-quoted string literal, `s`-interpolated if `interpolate` is set or the text
+    /// can't be written without escapes.
     StrLit {
-        value: String,
+        parts: Vec<StrPart>,
         interpolate: bool,
     },
     Var {
         name: Ident,
     },
+    Call {
+        function: Path,
+        args: Vec<Expr>,
+    },
+    /// Code written out as is, one statement per line.
+    Raw(String),
 }
 
 impl ToCode for Expr {
-    fn to_code(&self, out: &mut String, indent: usize) {
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect) {
         match self {
             Expr::Match { expr, clauses } => {
-                expr.to_code(out, indent);
+                expr.to_code(out, indent, dialect);
                 writeln!(out, 0, " match {{");
                 for clause in clauses {
-                    clause.to_code(out, indent + 2);
+                    clause.to_code(out, indent + 2, dialect);
                 }
                 writeln!(out, indent, "}}");
             }
-            Expr::StrLit { value, interpolate } => {
-                for line in value.split('\n').with_position() {
-                    let start = if *interpolate { "s" } else { "" };
-
-                    match line {
-                        Position::Only(line) => {
-                            write!(
-                                out,
-                                indent,
-                                "{s}\"\"\"{value}\"\"\"",
-                                s = start,
-                                value = line
-                            );
+            Expr::StrLit { parts, interpolate } => {
+                let interpolate = *interpolate || parts.iter().any(StrPart::needs_interpolation);
+
+                let mut code = String::new();
+                for (idx, part) in parts.iter().enumerate() {
+                    match part {
+                        StrPart::Text(text) if interpolate => {
+                            let is_last = idx == parts.len() - 1;
+                            escape_interpolated(text, is_last, &mut code);
                         }
-                        Position::First(line) => {
-                            write!(out, indent, "{s}\"\"\"{value}\n", s = start, value = line);
+                        StrPart::Text(text) => code.push_str(text),
+                        StrPart::Expr(expr) => {
+                            code.push_str("${");
+                            expr.to_code(&mut code, 0, dialect);
+                            code.push('}');
                         }
-                        Position::Middle(line) => {
-                            write!(out, 0, "{}\n", line);
+                    }
+                }
+
+                let start = if interpolate { "s" } else { "" };
+                write!(out, indent, "{}\"\"\"{}\"\"\"", start, code);
+            }
+            Expr::Var { name } => {
+                name.to_code(out, indent, dialect);
+            }
+            Expr::Call { function, args } => {
+                function.to_code(out, indent, dialect);
+                write!(out, 0, "(");
+                for arg in args.iter().with_position() {
+                    match arg {
+                        Position::First(arg) | Position::Middle(arg) => {
+                            arg.to_code(out, 0, dialect);
+                            write!(out, 0, ", ");
                         }
-                        Position::Last(line) => {
-                            write!(out, 0, "{}\"\"\"", line);
+                        Position::Last(arg) | Position::Only(arg) => arg.to_code(out, 0, dialect),
+                    }
+                }
+                write!(out, 0, ")");
+            }
+            Expr::Raw(code) => {
+                for line in code.lines().with_position() {
+                    match line {
+                        Position::First(line) | Position::Middle(line) => {
+                            writeln!(out, indent, "{}", line)
+                        }
+                        Position::Last(line) | Position::Only(line) => {
+                            write!(out, indent, "{}", line)
                         }
                     }
                 }
             }
-            Expr::Var { name } => {
-                name.to_code(out, indent);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum StrPart {
+    Text(String),
+    Expr(Expr),
+}
+
+impl StrPart {
+    /// Whether the part can only be written in an `s`-interpolated literal.
+    ///
+    /// A raw triple-quoted literal can't contain `"""` and a trailing quote would be confused
+    /// with the closing quotes.
+    fn needs_interpolation(&self) -> bool {
+        match self {
+            StrPart::Text(text) => text.contains("\"\"\"") || text.ends_with('"'),
+            StrPart::Expr(_) => true,
+        }
+    }
+}
+
+/// Write text into an `s`-interpolated triple-quoted literal.
+///
+/// `$` and `\` would otherwise be interpreted by the interpolator, and quotes that would end
+/// the literal are spliced in as expressions. That's a quote completing a run of three, or a
+/// quote at the end of the literal if `is_last` is set.
+fn escape_interpolated(text: &str, is_last: bool, out: &mut String) {
+    const QUOTE: &str = "${\"\\\"\"}";
+
+    let trailing_quotes = if is_last {
+        text.len() - text.trim_end_matches('"').len()
+    } else {
+        0
+    };
+    let (text, trailing) = text.split_at(text.len() - trailing_quotes);
+
+    let mut quotes_in_a_row = 0;
+    for c in text.chars() {
+        match c {
+            '$' => out.push_str("$$"),
+            '\\' => out.push_str("\\\\"),
+            '"' if quotes_in_a_row == 2 => {
+                out.push_str(QUOTE);
+                quotes_in_a_row = 0;
+                continue;
             }
+            c => out.push(c),
         }
+
+        if c == '"' {
+            quotes_in_a_row += 1;
+        } else {
+            quotes_in_a_row = 0;
+        }
+    }
+
+    for _ in trailing.chars() {
+        out.push_str(QUOTE);
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MatchClause {
     pub pattern: String,
     pub expr: Expr,
 }
 
 impl ToCode for MatchClause {
-    fn to_code(&self, out: &mut String, indent: usize) {
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect) {
         writeln!(out, indent, "case {} => {{", self.pattern);
-        self.expr.to_code(out, indent + 2);
+        self.expr.to_code(out, indent + 2, dialect);
         write!(out, 0, "\n");
         writeln!(out, indent, "}}");
     }
 }
 
+/// Whether `ident` is reserved in Scala 2 or Scala 3, or is one of Scala 3's soft keywords.
+///
+/// Soft keywords can be used as identifiers in most places, but not all, so they're quoted too.
 fn is_keyword(ident: &str) -> bool {
     thread_local! {
         static SCALA_KEYWORDS: RefCell<Vec<&'static str>> = RefCell::new(
@@ -197,6 +362,7 @@ fn is_keyword(ident: &str) -> bool {
                 "implicit",
                 "import",
                 "lazy",
+                "macro",
                 "match",
                 "new",
                 "null",
@@ -218,7 +384,23 @@ fn is_keyword(ident: &str) -> bool {
                 "var",
                 "while",
                 "with",
-                "yield"
+                "yield",
+                // Reserved in Scala 3
+                "enum",
+                "export",
+                "given",
+                "then",
+                // Soft keywords in Scala 3
+                "as",
+                "derives",
+                "end",
+                "extension",
+                "infix",
+                "inline",
+                "opaque",
+                "open",
+                "transparent",
+                "using"
             ]
         );
     }
@@ -229,20 +411,20 @@ fn is_keyword(ident: &str) -> bool {
     })
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Param {
     pub name: Ident,
     pub ty: String,
 }
 
 impl ToCode for Param {
-    fn to_code(&self, out: &mut String, indent: usize) {
-        self.name.to_code(out, indent);
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect) {
+        self.name.to_code(out, indent, dialect);
         write!(out, 0, ": {}", self.ty);
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Comment {
     pub text: String,
 }
@@ -256,12 +438,12 @@ impl Comment {
 }
 
 impl ToCode for Comment {
-    fn to_code(&self, out: &mut String, indent: usize) {
+    fn to_code(&self, out: &mut String, indent: usize, _dialect: Dialect) {
         writeln!(out, indent, "// {}", self.text);
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct MethodDef {
     pub name: Ident,
     pub params: Vec<Param>,
@@ -272,39 +454,45 @@ pub struct MethodDef {
 }
 
 impl ToCode for MethodDef {
-    fn to_code(&self, out: &mut String, indent: usize) {
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect) {
         if let Some(comment) = &self.comment {
-            comment.to_code(out, indent);
+            comment.to_code(out, indent, dialect);
         }
 
         write!(out, indent, "def ");
-        self.name.to_code(out, 0);
+        self.name.to_code(out, 0, dialect);
 
         if !self.params.is_empty() {
             write!(out, 0, "(");
-            self.params.to_code(out, 0);
+            self.params.to_code(out, 0, dialect);
             write!(out, 0, ")");
         }
 
         if !self.implicit_params.is_empty() {
-            write!(out, 0, "(implicit ");
-            self.implicit_params.to_code(out, 0);
+            match dialect {
+                Dialect::Scala2 => write!(out, 0, "(implicit "),
+                Dialect::Scala3 => write!(out, 0, "(using "),
+            }
+            self.implicit_params.to_code(out, 0, dialect);
             write!(out, 0, ")");
         }
 
         writeln!(out, 0, ": {} = {{", self.return_type);
-        self.body.to_code(out, indent + 2);
+        self.body.to_code(out, indent + 2, dialect);
+        if !out.ends_with('\n') {
+            write!(out, 0, "\n");
+        }
         write!(out, indent, "}}");
     }
 }
 
 impl ToCode for Vec<Param> {
-    fn to_code(&self, out: &mut String, _indent: usize) {
+    fn to_code(&self, out: &mut String, _indent: usize, dialect: Dialect) {
         for param in self.iter().with_position() {
             match param {
-                Position::Only(param) | Position::Last(param) => param.to_code(out, 0),
+                Position::Only(param) | Position::Last(param) => param.to_code(out, 0, dialect),
                 Position::First(param) | Position::Middle(param) => {
-                    param.to_code(out, 0);
+                    param.to_code(out, 0, dialect);
                     write!(out, 0, ", ");
                 }
             }
@@ -313,60 +501,74 @@ impl ToCode for Vec<Param> {
 }
 
 fn spaces(count: usize) -> String {
-    std::iter::repeat(" ").take(count).collect()
+    " ".repeat(count)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TopLevel {
     pub items: Vec<Item>,
 }
 
 impl ToCode for TopLevel {
-    fn to_code(&self, out: &mut String, indent: usize) {
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect) {
         for item in self.items.iter().with_position() {
             match item {
                 Position::First(item) | Position::Middle(item) => {
-                    item.to_code(out, indent);
+                    item.to_code(out, indent, dialect);
                     write!(out, 0, "\n\n")
                 }
-                Position::Last(item) | Position::Only(item) => item.to_code(out, indent),
+                Position::Last(item) | Position::Only(item) => item.to_code(out, indent, dialect),
             }
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Item {
     Package {
         segments: Vec<Ident>,
     },
     Object {
         case: bool,
-        name: String,
+        name: Ident,
         items: Vec<Item>,
         methods: Vec<MethodDef>,
-        super_type: Option<String>,
+        super_type: Option<Ident>,
     },
     Trait {
-        name: String,
+        name: Ident,
         sealed: bool,
     },
+    CaseClass {
+        name: Ident,
+        params: Vec<Param>,
+    },
+    /// A type with a fixed set of cases, and `methods` in its companion object.
+    ///
+    /// Scala 3 has `enum`s for this. In Scala 2 it's a sealed trait and an object with an
+    /// object per case, which are `case object`s if `case_objects` is set.
+    Enum {
+        name: Ident,
+        cases: Vec<Ident>,
+        case_objects: bool,
+        methods: Vec<MethodDef>,
+    },
     Comment(Comment),
 }
 
 impl ToCode for Item {
-    fn to_code(&self, out: &mut String, indent: usize) {
+    fn to_code(&self, out: &mut String, indent: usize, dialect: Dialect) {
         match self {
             Item::Package { segments } => {
                 write!(out, indent, "package ");
                 for segment in segments.iter().with_position() {
                     match segment {
                         Position::First(segment) | Position::Middle(segment) => {
-                            segment.to_code(out, 0);
+                            segment.to_code(out, 0, dialect);
                             write!(out, 0, ".")
                         }
                         Position::Last(segment) | Position::Only(segment) => {
-                            segment.to_code(out, 0)
+                            segment.to_code(out, 0, dialect)
                         }
                     }
                 }
@@ -383,10 +585,12 @@ impl ToCode for Item {
                 if *case {
                     write!(out, 0, "case ");
                 }
-                write!(out, 0, "object {}", name);
+                write!(out, 0, "object ");
+                name.to_code(out, 0, dialect);
 
                 if let Some(super_type) = super_type {
-                    write!(out, 0, " extends {}", super_type);
+                    write!(out, 0, " extends ");
+                    super_type.to_code(out, 0, dialect);
                 }
 
                 if !items.is_empty() || !methods.is_empty() {
@@ -395,27 +599,27 @@ impl ToCode for Item {
                     for item in items.iter().with_position() {
                         match item {
                             Position::First(item) | Position::Middle(item) => {
-                                item.to_code(out, indent + 2);
+                                item.to_code(out, indent + 2, dialect);
                                 writeln!(out, 0, "\n")
                             }
                             Position::Last(item) | Position::Only(item) => {
-                                item.to_code(out, indent + 2);
+                                item.to_code(out, indent + 2, dialect);
                             }
                         }
                     }
 
                     if !items.is_empty() && !methods.is_empty() {
-                        write!(out, 0, "\n");
+                        write!(out, 0, "\n\n");
                     }
 
                     for method in methods.iter().with_position() {
                         match method {
                             Position::First(method) | Position::Middle(method) => {
-                                method.to_code(out, indent + 2);
+                                method.to_code(out, indent + 2, dialect);
                                 writeln!(out, 0, "\n")
                             }
                             Position::Last(method) | Position::Only(method) => {
-                                method.to_code(out, indent + 2);
+                                method.to_code(out, indent + 2, dialect);
                             }
                         }
                     }
@@ -430,11 +634,72 @@ impl ToCode for Item {
                 if *sealed {
                     write!(out, 0, "sealed ");
                 }
-                write!(out, 0, "trait {}", name);
+                write!(out, 0, "trait ");
+                name.to_code(out, 0, dialect);
+            }
+
+            Item::CaseClass { name, params } => {
+                write!(out, indent, "final case class ");
+                name.to_code(out, 0, dialect);
+                write!(out, 0, "(");
+                params.to_code(out, 0, dialect);
+                write!(out, 0, ")");
             }
 
+            Item::Enum {
+                name,
+                cases,
+                case_objects,
+                methods,
+            } => match dialect {
+                Dialect::Scala2 => {
+                    let items = vec![
+                        Item::Trait {
+                            name: name.clone(),
+                            sealed: true,
+                        },
+                        Item::Object {
+                            case: false,
+                            name: name.clone(),
+                            items: cases
+                                .iter()
+                                .map(|case| Item::Object {
+                                    case: *case_objects,
+                                    name: case.clone(),
+                                    items: vec![],
+                                    methods: vec![],
+                                    super_type: Some(name.clone()),
+                                })
+                                .collect(),
+                            methods: methods.clone(),
+                            super_type: None,
+                        },
+                    ];
+                    TopLevel { items }.to_code(out, indent, dialect);
+                }
+                Dialect::Scala3 => {
+                    writeln!(out, indent, "enum {} {{", name);
+                    for case in cases {
+                        writeln!(out, indent + 2, "case {}", case);
+                    }
+                    write!(out, indent, "}}");
+
+                    if !methods.is_empty() {
+                        write!(out, 0, "\n\n");
+                        let companion = Item::Object {
+                            case: false,
+                            name: name.clone(),
+                            items: vec![],
+                            methods: methods.clone(),
+                            super_type: None,
+                        };
+                        companion.to_code(out, indent, dialect);
+                    }
+                }
+            },
+
             Item::Comment(comment) => {
-                comment.to_code(out, indent);
+                comment.to_code(out, indent, dialect);
             }
         }
     }
@@ -0,0 +1,63 @@
+use crate::lokalise_client::{Key, Project};
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+/// Version of the snapshot format.
+///
+/// Bump this when changing the format in a way older snapshots can't be read with.
+const VERSION: u32 = 1;
+
+/// Keys downloaded from Lokalise, stored so code can be generated without talking to Lokalise.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Snapshot {
+    version: u32,
+    projects: Vec<ProjectKeys>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectKeys {
+    project: Project,
+    keys: Vec<Key>,
+}
+
+impl Snapshot {
+    pub fn new(projects: Vec<(Project, Vec<Key>)>) -> Self {
+        Self {
+            version: VERSION,
+            projects: projects
+                .into_iter()
+                .map(|(project, keys)| ProjectKeys { project, keys })
+                .collect(),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+
+        let Version { version } = serde_json::from_str(json)?;
+        if version != VERSION {
+            return Err(Error::msg(format!(
+                "Unsupported snapshot version {}. This version of i18n-code-gen reads version {}",
+                version, VERSION
+            )));
+        }
+
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        Ok(json)
+    }
+
+    pub fn into_projects(self) -> Vec<(Project, Vec<Key>)> {
+        self.projects
+            .into_iter()
+            .map(|ProjectKeys { project, keys }| (project, keys))
+            .collect()
+    }
+}
@@ -0,0 +1,440 @@
+use crate::code_gen::{
+    language_tag, select_type_name, KeyTranslations, Message, PlaceholderKind, Segment, SelectType,
+    Translations,
+};
+use crate::config::Config;
+use crate::plural_rules::{self, PluralCategory};
+use anyhow::Result;
+use heck::{CamelCase, MixedCase};
+use std::fmt::Write;
+
+/// Generate a Swift file with an `enum` namespace containing a static function per key.
+///
+/// `Locale` and `Cardinality` are nested inside the namespace so they don't clash with
+/// `Foundation.Locale`.
+pub fn generate_code(translations: &Translations, config: &Config) -> Result<String> {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// This file is generated by i18n-code-gen. Don't edit it by hand."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+
+    let formatted = translations.uses_formatted_placeholders();
+    if formatted {
+        writeln!(out, "import Foundation").unwrap();
+        writeln!(out).unwrap();
+    }
+
+    writeln!(out, "public enum {} {{", escape_ident(config.object())).unwrap();
+
+    write_locale_enum(&mut out, &translations.locales);
+    writeln!(out).unwrap();
+    write_cardinality_enum(&mut out, &translations.locales)?;
+
+    for select in &translations.selects {
+        writeln!(out).unwrap();
+        write_select_enum(&mut out, select);
+    }
+
+    if formatted {
+        writeln!(out).unwrap();
+        write_format_enum(&mut out, &translations.locales);
+    }
+
+    for project in &translations.projects {
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "    public enum {} {{",
+            escape_ident(&project.name.to_camel_case())
+        )
+        .unwrap();
+
+        for (idx, key) in project.keys.iter().enumerate() {
+            if idx != 0 {
+                writeln!(out).unwrap();
+            }
+            write_function(&mut out, key);
+        }
+
+        writeln!(out, "    }}").unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+
+    Ok(out)
+}
+
+fn write_locale_enum(out: &mut String, locales: &[String]) {
+    writeln!(out, "    public enum Locale {{").unwrap();
+    for locale in locales {
+        writeln!(out, "        case {}", locale_name(locale)).unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+}
+
+fn write_select_enum(out: &mut String, select: &SelectType) {
+    writeln!(out, "    public enum {} {{", escape_ident(&select.name)).unwrap();
+    for case in &select.cases {
+        writeln!(out, "        case {}", escape_ident(&case.to_mixed_case())).unwrap();
+    }
+    writeln!(out, "    }}").unwrap();
+}
+
+fn write_cardinality_enum(out: &mut String, locales: &[String]) -> Result<()> {
+    writeln!(out, "    public enum Cardinality {{").unwrap();
+    for category in PluralCategory::ALL.iter() {
+        writeln!(out, "        case {}", category_name(*category)).unwrap();
+    }
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "        public static func of(_ count: Int, locale: Locale) -> Cardinality {{"
+    )
+    .unwrap();
+    writeln!(out, "            let n = abs(count)").unwrap();
+    writeln!(out, "            switch locale {{").unwrap();
+    for locale in locales {
+        writeln!(out, "            case .{}:", locale_name(locale)).unwrap();
+        for (category, condition) in plural_rules::rules_for(locale)? {
+            writeln!(
+                out,
+                "                if {} {{ return .{} }}",
+                condition.to_code("n"),
+                category_name(category)
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "                return .{}",
+            category_name(PluralCategory::Other)
+        )
+        .unwrap();
+    }
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "    }}").unwrap();
+
+    Ok(())
+}
+
+/// The `Money` type and the `Format` enum formatting placeholders according to the locale.
+fn write_format_enum(out: &mut String, locales: &[String]) {
+    out.push_str(
+        r#"    public struct Money {
+        public let amount: Decimal
+        public let currencyCode: String
+
+        public init(amount: Decimal, currencyCode: String) {
+            self.amount = amount
+            self.currencyCode = currencyCode
+        }
+    }
+
+    enum Format {
+        static func foundationLocale(_ locale: Locale) -> Foundation.Locale {
+            switch locale {
+"#,
+    );
+    for locale in locales {
+        writeln!(
+            out,
+            "            case .{}: return Foundation.Locale(identifier: \"{}\")",
+            locale_name(locale),
+            language_tag(locale)
+        )
+        .unwrap();
+    }
+    out.push_str(
+        r#"            }
+        }
+
+        static func number(_ value: Double, decimals: Int? = nil, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            if let decimals = decimals {
+                formatter.minimumFractionDigits = decimals
+                formatter.maximumFractionDigits = decimals
+            }
+            return formatter.string(from: NSNumber(value: value)) ?? "\(value)"
+        }
+
+        static func decimal(_ value: Decimal, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            formatter.minimumFractionDigits = max(-value.exponent, 0)
+            formatter.maximumFractionDigits = max(-value.exponent, 0)
+            return formatter.string(from: value as NSDecimalNumber) ?? "\(value)"
+        }
+
+        /// Formatted in UTC, so pass dates as midnight UTC.
+        static func date(_ value: Date, locale: Locale) -> String {
+            let formatter = DateFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.timeZone = TimeZone(identifier: "UTC")
+            formatter.dateStyle = .long
+            formatter.timeStyle = .none
+            return formatter.string(from: value)
+        }
+
+        static func money(_ value: Money, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .currency
+            formatter.currencyCode = value.currencyCode
+            return formatter.string(from: value.amount as NSDecimalNumber)
+                ?? "\(value.amount) \(value.currencyCode)"
+        }
+    }
+"#,
+    );
+}
+
+fn write_function(out: &mut String, key: &KeyTranslations) {
+    let mut params = vec!["locale: Locale".to_string()];
+    params.extend(key.arguments().into_iter().map(|argument| {
+        let ty = match argument.kind {
+            PlaceholderKind::Select => {
+                return format!(
+                    "{}: {}",
+                    escape_ident(&argument.name),
+                    escape_ident(&select_type_name(&argument.name))
+                )
+            }
+            PlaceholderKind::String => "String",
+            PlaceholderKind::Integer => "Int",
+            PlaceholderKind::Float { .. } => "Double",
+            PlaceholderKind::Decimal => "Decimal",
+            PlaceholderKind::Date => "Date",
+            PlaceholderKind::Money => "Money",
+        };
+        format!("{}: {}", escape_ident(&argument.name), ty)
+    }));
+
+    writeln!(out, "        // {}", key.name).unwrap();
+    writeln!(
+        out,
+        "        public static func {}({}) -> String {{",
+        escape_ident(&key.method_name),
+        params.join(", ")
+    )
+    .unwrap();
+    writeln!(out, "            switch locale {{").unwrap();
+
+    for value in &key.values {
+        writeln!(out, "            case .{}:", locale_name(&value.locale)).unwrap();
+        write_message(out, &value.message, 16);
+    }
+
+    writeln!(out, "            }}").unwrap();
+    writeln!(out, "        }}").unwrap();
+}
+
+/// Statements returning the text of a message, switching on the arguments picking its plural
+/// forms and cases.
+fn write_message(out: &mut String, message: &Message, indent: usize) {
+    let pad = " ".repeat(indent);
+    match message {
+        Message::Text(segments) => {
+            writeln!(out, "{}return {}", pad, string_lit(segments)).unwrap();
+        }
+        Message::Plural {
+            argument,
+            exact,
+            forms,
+            other,
+        } => {
+            let argument = escape_ident(argument);
+            let mut indent = indent;
+            if !exact.is_empty() {
+                writeln!(out, "{}switch {} {{", pad, argument).unwrap();
+                for (value, message) in exact {
+                    writeln!(out, "{}case {}:", pad, value).unwrap();
+                    write_message(out, message, indent + 4);
+                }
+                writeln!(out, "{}default:", pad).unwrap();
+                indent += 4;
+            }
+
+            let inner_pad = " ".repeat(indent);
+            writeln!(
+                out,
+                "{}switch Cardinality.of({}, locale: locale) {{",
+                inner_pad, argument
+            )
+            .unwrap();
+            for (category, form) in forms {
+                writeln!(out, "{}case .{}:", inner_pad, category_name(*category)).unwrap();
+                write_message(out, form, indent + 4);
+            }
+            writeln!(out, "{}default:", inner_pad).unwrap();
+            write_message(out, other, indent + 4);
+            writeln!(out, "{}}}", inner_pad).unwrap();
+
+            if !exact.is_empty() {
+                writeln!(out, "{}}}", pad).unwrap();
+            }
+        }
+        Message::Select {
+            argument,
+            cases,
+            other,
+        } => {
+            writeln!(out, "{}switch {} {{", pad, escape_ident(argument)).unwrap();
+            for (case, message) in cases {
+                writeln!(out, "{}case .{}:", pad, escape_ident(&case.to_mixed_case())).unwrap();
+                write_message(out, message, indent + 4);
+            }
+            writeln!(out, "{}default:", pad).unwrap();
+            write_message(out, other, indent + 4);
+            writeln!(out, "{}}}", pad).unwrap();
+        }
+    }
+}
+
+fn locale_name(locale: &str) -> String {
+    escape_ident(&locale.to_mixed_case())
+}
+
+fn category_name(category: PluralCategory) -> String {
+    category.name().to_lowercase()
+}
+
+/// A string literal using string interpolation for the placeholders.
+fn string_lit(segments: &[Segment]) -> String {
+    let mut out = String::from("\"");
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => {
+                for c in text.chars() {
+                    match c {
+                        '\\' => out.push_str("\\\\"),
+                        '"' => out.push_str("\\\""),
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        '\0' => out.push_str("\\0"),
+                        c => out.push(c),
+                    }
+                }
+            }
+            Segment::Placeholder(placeholder) => {
+                let name = escape_ident(&placeholder.name);
+                match placeholder.kind {
+                    PlaceholderKind::String
+                    | PlaceholderKind::Integer
+                    | PlaceholderKind::Select => write!(out, "\\({})", name).unwrap(),
+                    PlaceholderKind::Float { decimals: None } => {
+                        write!(out, "\\(Format.number({}, locale: locale))", name).unwrap()
+                    }
+                    PlaceholderKind::Float {
+                        decimals: Some(decimals),
+                    } => write!(
+                        out,
+                        "\\(Format.number({}, decimals: {}, locale: locale))",
+                        name, decimals
+                    )
+                    .unwrap(),
+                    PlaceholderKind::Decimal => {
+                        write!(out, "\\(Format.decimal({}, locale: locale))", name).unwrap()
+                    }
+                    PlaceholderKind::Date => {
+                        write!(out, "\\(Format.date({}, locale: locale))", name).unwrap()
+                    }
+                    PlaceholderKind::Money => {
+                        write!(out, "\\(Format.money({}, locale: locale))", name).unwrap()
+                    }
+                }
+            }
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Replace the characters that can't be part of an identifier, even quoted, like the `.` in
+/// `policy.start`, with `_`.
+pub fn replace_invalid_chars(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Quote keywords with backticks and prefix names starting with a digit with an underscore.
+fn escape_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "Any",
+        "Self",
+        "as",
+        "associatedtype",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "continue",
+        "default",
+        "defer",
+        "deinit",
+        "do",
+        "else",
+        "enum",
+        "extension",
+        "fallthrough",
+        "false",
+        "fileprivate",
+        "for",
+        "func",
+        "guard",
+        "if",
+        "import",
+        "in",
+        "init",
+        "inout",
+        "internal",
+        "is",
+        "let",
+        "nil",
+        "open",
+        "operator",
+        "private",
+        "protocol",
+        "public",
+        "repeat",
+        "rethrows",
+        "return",
+        "self",
+        "static",
+        "struct",
+        "subscript",
+        "super",
+        "switch",
+        "throw",
+        "throws",
+        "true",
+        "try",
+        "typealias",
+        "var",
+        "where",
+        "while",
+    ];
+
+    if KEYWORDS.contains(&name) {
+        format!("`{}`", name)
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,387 @@
+use crate::code_gen::{
+    select_type_name, KeyTranslations, Message, PlaceholderKind, Segment, SelectType, Translations,
+};
+use crate::plural_rules::{self, PluralCategory};
+use anyhow::Result;
+use heck::MixedCase;
+use std::fmt::Write;
+
+/// Generate a TypeScript module with a function per key.
+///
+/// Each project becomes an exported object of functions taking the locale as the first
+/// argument followed by the placeholders.
+pub fn generate_code(translations: &Translations) -> Result<String> {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// This file is generated by i18n-code-gen. Don't edit it by hand."
+    )
+    .unwrap();
+    writeln!(out, "/* eslint-disable */").unwrap();
+    writeln!(out).unwrap();
+
+    write_locale_type(&mut out, &translations.locales);
+    writeln!(out).unwrap();
+    write_cardinality(&mut out, &translations.locales)?;
+
+    for select in &translations.selects {
+        writeln!(out).unwrap();
+        write_select_type(&mut out, select);
+    }
+
+    if translations.uses_formatted_placeholders() {
+        writeln!(out).unwrap();
+        write_formatters(&mut out);
+    }
+
+    for project in &translations.projects {
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "export const {} = {{",
+            escape_ident(&project.name.to_mixed_case())
+        )
+        .unwrap();
+
+        for (idx, key) in project.keys.iter().enumerate() {
+            if idx != 0 {
+                writeln!(out).unwrap();
+            }
+            write_function(&mut out, key);
+        }
+
+        writeln!(out, "}};").unwrap();
+    }
+
+    Ok(out)
+}
+
+fn write_locale_type(out: &mut String, locales: &[String]) {
+    let union = if locales.is_empty() {
+        "never".to_string()
+    } else {
+        locales
+            .iter()
+            .map(|locale| string_lit(locale))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+    writeln!(out, "export type Locale = {};", union).unwrap();
+}
+
+fn write_cardinality(out: &mut String, locales: &[String]) -> Result<()> {
+    let categories = PluralCategory::ALL
+        .iter()
+        .map(|category| string_lit(&category.name().to_lowercase()))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    writeln!(out, "export type Cardinality = {};", categories).unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "export function cardinality(locale: Locale, count: number): Cardinality {{"
+    )
+    .unwrap();
+    writeln!(out, "  const n = Math.abs(count);").unwrap();
+    writeln!(out, "  switch (locale) {{").unwrap();
+    for locale in locales {
+        writeln!(out, "    case {}:", string_lit(locale)).unwrap();
+        for (category, condition) in plural_rules::rules_for(locale)? {
+            writeln!(
+                out,
+                "      if ({}) return {};",
+                condition.to_code("n"),
+                string_lit(&category.name().to_lowercase())
+            )
+            .unwrap();
+        }
+        writeln!(out, "      return \"other\";").unwrap();
+    }
+    writeln!(out, "  }}").unwrap();
+    writeln!(out, "}}").unwrap();
+
+    Ok(())
+}
+
+fn write_select_type(out: &mut String, select: &SelectType) {
+    let union = select
+        .cases
+        .iter()
+        .map(|case| string_lit(case))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    writeln!(out, "export type {} = {};", select.name, union).unwrap();
+}
+
+/// The `Money` type and functions formatting placeholders according to the locale.
+fn write_formatters(out: &mut String) {
+    out.push_str(
+        r#"export type Money = { amount: number; currency: string };
+
+function formatNumber(locale: Locale, value: number, decimals?: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    minimumFractionDigits: decimals,
+    maximumFractionDigits: decimals,
+  }).format(value);
+}
+
+function formatDecimal(locale: Locale, value: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    maximumFractionDigits: 20,
+  }).format(value);
+}
+
+// Formatted in UTC, so pass dates as midnight UTC, like `new Date(Date.UTC(2021, 2, 1))`.
+function formatDate(locale: Locale, value: Date): string {
+  return new Intl.DateTimeFormat(locale.replace("_", "-"), {
+    year: "numeric",
+    month: "long",
+    day: "numeric",
+    timeZone: "UTC",
+  }).format(value);
+}
+
+function formatMoney(locale: Locale, value: Money): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    style: "currency",
+    currency: value.currency,
+  }).format(value.amount);
+}
+"#,
+    );
+}
+
+fn write_function(out: &mut String, key: &KeyTranslations) {
+    let mut params = vec!["locale: Locale".to_string()];
+    params.extend(key.arguments().into_iter().map(|argument| {
+        let ty = match argument.kind {
+            PlaceholderKind::Select => {
+                return format!(
+                    "{}: {}",
+                    escape_ident(&argument.name),
+                    select_type_name(&argument.name)
+                )
+            }
+            PlaceholderKind::String => "string",
+            PlaceholderKind::Integer | PlaceholderKind::Float { .. } | PlaceholderKind::Decimal => {
+                "number"
+            }
+            PlaceholderKind::Date => "Date",
+            PlaceholderKind::Money => "Money",
+        };
+        format!("{}: {}", escape_ident(&argument.name), ty)
+    }));
+
+    writeln!(out, "  // {}", key.name).unwrap();
+    writeln!(
+        out,
+        "  {}({}): string {{",
+        escape_ident(&key.method_name),
+        params.join(", ")
+    )
+    .unwrap();
+    writeln!(out, "    switch (locale) {{").unwrap();
+
+    for value in &key.values {
+        writeln!(out, "      case {}:", string_lit(&value.locale)).unwrap();
+        write_message(out, &value.message, 8);
+    }
+
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "  }},").unwrap();
+}
+
+/// Statements returning the text of a message, switching on the arguments picking its plural
+/// forms and cases.
+fn write_message(out: &mut String, message: &Message, indent: usize) {
+    let pad = " ".repeat(indent);
+    match message {
+        Message::Text(segments) => {
+            writeln!(out, "{}return {};", pad, template_lit(segments)).unwrap();
+        }
+        Message::Plural {
+            argument,
+            exact,
+            forms,
+            other,
+        } => {
+            let argument = escape_ident(argument);
+            let mut indent = indent;
+            if !exact.is_empty() {
+                writeln!(out, "{}switch ({}) {{", pad, argument).unwrap();
+                for (value, message) in exact {
+                    writeln!(out, "{}  case {}:", pad, value).unwrap();
+                    write_message(out, message, indent + 4);
+                }
+                writeln!(out, "{}  default:", pad).unwrap();
+                indent += 4;
+            }
+
+            let inner_pad = " ".repeat(indent);
+            writeln!(
+                out,
+                "{}switch (cardinality(locale, {})) {{",
+                inner_pad, argument
+            )
+            .unwrap();
+            for (category, form) in forms {
+                writeln!(
+                    out,
+                    "{}  case {}:",
+                    inner_pad,
+                    string_lit(&category.name().to_lowercase())
+                )
+                .unwrap();
+                write_message(out, form, indent + 4);
+            }
+            writeln!(out, "{}  default:", inner_pad).unwrap();
+            write_message(out, other, indent + 4);
+            writeln!(out, "{}}}", inner_pad).unwrap();
+
+            if !exact.is_empty() {
+                writeln!(out, "{}}}", pad).unwrap();
+            }
+        }
+        Message::Select {
+            argument,
+            cases,
+            other,
+        } => {
+            writeln!(out, "{}switch ({}) {{", pad, escape_ident(argument)).unwrap();
+            for (case, message) in cases {
+                writeln!(out, "{}  case {}:", pad, string_lit(case)).unwrap();
+                write_message(out, message, indent + 4);
+            }
+            writeln!(out, "{}  default:", pad).unwrap();
+            write_message(out, other, indent + 4);
+            writeln!(out, "{}}}", pad).unwrap();
+        }
+    }
+}
+
+fn string_lit(s: &str) -> String {
+    serde_json::to_string(s).unwrap()
+}
+
+fn template_lit(segments: &[Segment]) -> String {
+    let mut out = String::from("`");
+    for segment in segments {
+        match segment {
+            Segment::Text(text) => {
+                let mut chars = text.chars().peekable();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '\\' => out.push_str("\\\\"),
+                        '`' => out.push_str("\\`"),
+                        '$' if chars.peek() == Some(&'{') => out.push_str("\\$"),
+                        c => out.push(c),
+                    }
+                }
+            }
+            Segment::Placeholder(placeholder) => {
+                let name = escape_ident(&placeholder.name);
+                match placeholder.kind {
+                    PlaceholderKind::String
+                    | PlaceholderKind::Integer
+                    | PlaceholderKind::Select => write!(out, "${{{}}}", name).unwrap(),
+                    PlaceholderKind::Float {
+                        decimals: Some(decimals),
+                    } => write!(out, "${{formatNumber(locale, {}, {})}}", name, decimals).unwrap(),
+                    PlaceholderKind::Float { decimals: None } => {
+                        write!(out, "${{formatNumber(locale, {})}}", name).unwrap()
+                    }
+                    PlaceholderKind::Decimal => {
+                        write!(out, "${{formatDecimal(locale, {})}}", name).unwrap()
+                    }
+                    PlaceholderKind::Date => {
+                        write!(out, "${{formatDate(locale, {})}}", name).unwrap()
+                    }
+                    PlaceholderKind::Money => {
+                        write!(out, "${{formatMoney(locale, {})}}", name).unwrap()
+                    }
+                }
+            }
+        }
+    }
+    out.push('`');
+    out
+}
+
+/// Replace the characters that can't be part of an identifier, like the `.` in `policy.start`,
+/// with `_`.
+pub fn replace_invalid_chars(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Make a name usable as a parameter or variable.
+///
+/// Reserved words get a trailing underscore and names starting with a digit a leading one.
+fn escape_ident(name: &str) -> String {
+    const RESERVED: &[&str] = &[
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "import",
+        "in",
+        "instanceof",
+        "locale",
+        "new",
+        "null",
+        "return",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+        "let",
+        "static",
+        "implements",
+        "interface",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "await",
+    ];
+
+    if RESERVED.contains(&name) {
+        format!("{}_", name)
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,341 @@
+use crate::code_gen::{KeyTranslations, Message, PlaceholderKind, Translations};
+use crate::config::ValidationConfig;
+use anyhow::{Error, Result};
+use std::{collections::BTreeMap, fmt};
+
+/// A placeholder in one translation of a key that doesn't agree with the other translations.
+#[derive(Debug)]
+pub struct PlaceholderIssue {
+    pub project: String,
+    pub key: String,
+    pub locale: String,
+    pub problem: PlaceholderProblem,
+}
+
+#[derive(Debug)]
+pub enum PlaceholderProblem {
+    /// The placeholder is used by other translations but not this one.
+    Missing(String),
+    /// The placeholder isn't used by the translation in the reference locale.
+    Extra(String),
+    /// The placeholder is used with another kind in other translations, or in this one.
+    ///
+    /// The generated function can only have one parameter per name, so this is always an
+    /// error.
+    ConflictingKind {
+        name: String,
+        kinds: Vec<PlaceholderKind>,
+    },
+}
+
+impl PlaceholderProblem {
+    fn is_error(&self) -> bool {
+        matches!(self, PlaceholderProblem::ConflictingKind { .. })
+    }
+}
+
+impl fmt::Display for PlaceholderIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{} [{}]: ", self.project, self.key, self.locale)?;
+        match &self.problem {
+            PlaceholderProblem::Missing(name) => write!(f, "missing placeholder `{}`", name),
+            PlaceholderProblem::Extra(name) => write!(f, "extra placeholder `{}`", name),
+            PlaceholderProblem::ConflictingKind { name, kinds } => match &kinds[..] {
+                [kind] => write!(
+                    f,
+                    "placeholder `{}` is `%{}` but other translations use another kind",
+                    name,
+                    kind.code()
+                ),
+                kinds => write!(
+                    f,
+                    "placeholder `{}` is used as {}",
+                    name,
+                    kinds
+                        .iter()
+                        .map(|kind| format!("`%{}`", kind.code()))
+                        .collect::<Vec<_>>()
+                        .join(" and ")
+                ),
+            },
+        }
+    }
+}
+
+/// Check that every translation of a key uses the same placeholders.
+///
+/// Problems are printed as warnings, or returned as an error if `strict` is set. Placeholders
+/// used with different kinds are always an error.
+pub fn check_placeholders(translations: &Translations, config: &ValidationConfig) -> Result<()> {
+    let issues = translations
+        .projects
+        .iter()
+        .flat_map(|project| {
+            project.keys.iter().flat_map(move |key| {
+                key_issues(key, config.reference_locale.as_deref())
+                    .into_iter()
+                    .map(move |(locale, problem)| PlaceholderIssue {
+                        project: project.name.clone(),
+                        key: key.name.clone(),
+                        locale,
+                        problem,
+                    })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let (errors, warnings) = issues
+        .into_iter()
+        .partition::<Vec<_>, _>(|issue| config.strict || issue.problem.is_error());
+
+    for issue in &warnings {
+        eprintln!("warning: {}", issue);
+    }
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let report = errors
+        .iter()
+        .map(|issue| format!("  {}", issue))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Err(Error::msg(format!(
+        "Found {} placeholder problem(s):\n{}",
+        errors.len(),
+        report
+    )))
+}
+
+/// The problems with each translation of a key, paired with its locale.
+///
+/// Translations are compared to the one in `reference_locale` if the key has one, otherwise to
+/// the placeholders used by any translation. Texts filled in for missing translations are left
+/// out, since those are reported on their own.
+fn key_issues(
+    key: &KeyTranslations,
+    reference_locale: Option<&str>,
+) -> Vec<(String, PlaceholderProblem)> {
+    let used = key
+        .values
+        .iter()
+        .filter(|value| !value.filled_in)
+        .map(|value| (value.locale.as_str(), used_placeholders(&value.message)))
+        .collect::<Vec<_>>();
+
+    let reference = reference_locale.and_then(|reference_locale| {
+        used.iter()
+            .find(|(locale, _)| *locale == reference_locale)
+            .map(|(_, placeholders)| placeholders)
+    });
+
+    let mut all = BTreeMap::<&str, Vec<PlaceholderKind>>::new();
+    for (_, placeholders) in &used {
+        for (name, kinds) in placeholders {
+            let all_kinds = all.entry(name.as_str()).or_default();
+            for kind in kinds {
+                if !all_kinds.iter().any(|other| same_parameter(*other, *kind)) {
+                    all_kinds.push(*kind);
+                }
+            }
+        }
+    }
+
+    // Plural keys always take a `count`, whether or not the translation shows it.
+    let is_implicit = |name: &str| key.plural && name == "count";
+
+    let mut issues = Vec::new();
+    for (locale, placeholders) in &used {
+        let expected = match reference {
+            Some(reference) => reference.keys().map(String::as_str).collect::<Vec<_>>(),
+            None => all.keys().copied().collect::<Vec<_>>(),
+        };
+
+        for name in expected {
+            if !placeholders.contains_key(name) && !is_implicit(name) {
+                issues.push((
+                    locale.to_string(),
+                    PlaceholderProblem::Missing(name.to_string()),
+                ));
+            }
+        }
+
+        for (name, kinds) in placeholders {
+            if let Some(reference) = reference {
+                if !reference.contains_key(name) && !is_implicit(name) {
+                    issues.push((
+                        locale.to_string(),
+                        PlaceholderProblem::Extra(name.to_string()),
+                    ));
+                }
+            }
+
+            if all.get(name.as_str()).is_some_and(|kinds| kinds.len() > 1) {
+                issues.push((
+                    locale.to_string(),
+                    PlaceholderProblem::ConflictingKind {
+                        name: name.to_string(),
+                        kinds: kinds.clone(),
+                    },
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// The kinds of the placeholders in all forms of a message, and of the arguments it matches
+/// on, by name.
+fn used_placeholders(message: &Message) -> BTreeMap<String, Vec<PlaceholderKind>> {
+    let mut used = BTreeMap::<String, Vec<PlaceholderKind>>::new();
+
+    let placeholders = message
+        .placeholders()
+        .into_iter()
+        .map(|placeholder| (placeholder.name.clone(), placeholder.kind));
+    let selectors = message
+        .selectors()
+        .into_iter()
+        .map(|selector| (selector.name, selector.kind));
+
+    for (name, kind) in placeholders.chain(selectors) {
+        let kinds = used.entry(name).or_default();
+        if !kinds.iter().any(|other| same_parameter(*other, kind)) {
+            kinds.push(kind);
+        }
+    }
+
+    used
+}
+
+/// Whether placeholders of the two kinds can be the same parameter.
+///
+/// Numbers shown with a different number of decimals are both `Double`s.
+fn same_parameter(a: PlaceholderKind, b: PlaceholderKind) -> bool {
+    matches!(
+        (a, b),
+        (PlaceholderKind::Float { .. }, PlaceholderKind::Float { .. })
+    ) || a == b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::key_issues;
+    use crate::code_gen::{
+        generate_code, KeyTranslations, LocalizedMessage, Message, Output, Placeholder,
+        PlaceholderKind, Segment,
+    };
+    use crate::config::{Config, Target};
+    use crate::snapshot::Snapshot;
+    use anyhow::Result;
+
+    /// Generate Scala for a key translated to `da` and `en`.
+    fn generate(da: &str, en: &str, strict: bool) -> Result<String> {
+        let json = serde_json::json!({
+            "version": 1,
+            "projects": [{
+                "project": { "project_id": "1111.aaaa", "name": "Undo" },
+                "keys": [{
+                    "key_id": 1,
+                    "key_name": {
+                        "ios": "greeting",
+                        "android": "greeting",
+                        "web": "greeting",
+                        "other": "greeting"
+                    },
+                    "is_plural": false,
+                    "translations": [
+                        { "language_iso": "da", "translation": da },
+                        { "language_iso": "en", "translation": en }
+                    ]
+                }]
+            }]
+        });
+        let projects = Snapshot::from_json(&json.to_string())?.into_projects();
+
+        let mut config = Config::default();
+        config.validation.strict = strict;
+        match generate_code(projects, &config, Target::Scala)? {
+            Output::File(code) => Ok(code),
+            Output::Files(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn missing_placeholders_are_warnings() {
+        assert!(generate("Hej", "Hi [%s:name]", false).is_ok());
+        assert!(generate("Hej", "Hi [%s:name]", true).is_err());
+    }
+
+    #[test]
+    fn missing_translations_are_empty_unless_strict() {
+        let code = generate("", "Hi", false).unwrap();
+        assert!(code.contains("case Locale.Da => {\n          \"\"\"\"\"\"\n"));
+        assert!(generate("", "Hi", true)
+            .unwrap_err()
+            .to_string()
+            .starts_with("Keys are missing translations:\n  Undo/greeting: da\n"));
+    }
+
+    #[test]
+    fn missing_translations_of_keys_with_placeholders_are_only_reported_once() {
+        let err = generate("", "Hi [%s:name]", true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Keys are missing translations:\n  Undo/greeting: da\n\
+             Translate them or set `fallback_locale` in the config to use another locale's text."
+        );
+
+        let name = Placeholder {
+            name: "name".to_string(),
+            kind: PlaceholderKind::String,
+            matched: "[%s:name]".to_string(),
+        };
+        let key = KeyTranslations {
+            name: "greeting".to_string(),
+            method_name: "greeting".to_string(),
+            plural: false,
+            placeholders: vec![name.clone()],
+            values: vec![
+                LocalizedMessage {
+                    locale: "en".to_string(),
+                    message: Message::Text(vec![Segment::Placeholder(name)]),
+                    filled_in: false,
+                },
+                LocalizedMessage {
+                    locale: "da".to_string(),
+                    message: Message::Text(Vec::new()),
+                    filled_in: true,
+                },
+            ],
+        };
+        assert!(key_issues(&key, None).is_empty());
+    }
+
+    #[test]
+    fn conflicting_kinds_across_translations_are_errors() {
+        let err = generate("Hej [%i:name]", "Hi [%s:name]", false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Found 2 placeholder problem(s):\n  \
+             Undo/greeting [da]: placeholder `name` is `%i` but other translations use another kind\n  \
+             Undo/greeting [en]: placeholder `name` is `%s` but other translations use another kind"
+        );
+    }
+
+    #[test]
+    fn conflicting_kinds_within_a_translation_are_errors() {
+        let err = generate("Hej [%s:name]", "Hi [%s:name], [%i:name]", false).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Undo/greeting [en]: placeholder `name` is used as `%s` and `%i`"));
+    }
+
+    #[test]
+    fn numbers_with_different_decimals_are_the_same_parameter() {
+        let code = generate("[%.2f:km] km", "[%f:km] km", true).unwrap();
+        assert!(code.contains("def greeting(km: Double)"));
+    }
+}
@@ -0,0 +1,115 @@
+//! Generates code for each case in `tests/golden` and compares it to the expected output.
+//!
+//! Each case is a directory containing
+//!
+//! - `input.json`, a snapshot of keys as written by `i18n-code-gen fetch`,
+//! - `expected.scala`, the Scala that should be generated from it,
+//! - optionally `expected.ts`, `expected.kt` and `expected.swift`, the code that should be
+//!   generated for the other targets, and
+//! - optionally `config.toml`, the config to generate with.
+//!
+//! Run with `BLESS=1` to overwrite the expected files with what's generated instead. Add an
+//! empty expected file to start testing a case for another target.
+
+use similar::TextDiff;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The targets and the extensions of their expected files.
+const TARGETS: &[(&str, &str)] = &[
+    ("scala", "scala"),
+    ("typescript", "ts"),
+    ("kotlin", "kt"),
+    ("swift", "swift"),
+];
+
+#[test]
+fn golden() {
+    let bless = env::var_os("BLESS").is_some();
+    let cases_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden");
+
+    let mut cases = fs::read_dir(&cases_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect::<Vec<_>>();
+    cases.sort();
+    assert!(!cases.is_empty(), "No cases in {}", cases_dir.display());
+
+    let mut failures = Vec::new();
+    for case in &cases {
+        let name = case.file_name().unwrap().to_string_lossy().to_string();
+
+        for &(target, extension) in TARGETS {
+            let file_name = format!("expected.{}", extension);
+            let expected_path = case.join(&file_name);
+            if target != "scala" && !expected_path.exists() {
+                continue;
+            }
+
+            let actual = generate(&name, case, target);
+
+            if bless {
+                fs::write(&expected_path, &actual).unwrap();
+                continue;
+            }
+
+            let expected = fs::read_to_string(&expected_path).unwrap_or_default();
+            if actual != expected {
+                let diff = TextDiff::from_lines(expected.as_str(), actual.as_str())
+                    .unified_diff()
+                    .header(&file_name, "generated")
+                    .to_string();
+                failures.push(format!("{}:\n{}", name, diff));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Generated code differs from the expected output. Run with BLESS=1 to update it.\n\n{}",
+        failures.join("\n")
+    );
+}
+
+fn generate(name: &str, case: &Path, target: &str) -> String {
+    let config = case.join("config.toml");
+    let config = if config.exists() {
+        config
+    } else {
+        empty_config(name)
+    };
+
+    let output = Command::new(env!("CARGO_BIN_EXE_i18n-code-gen"))
+        .arg("generate")
+        .arg("--stdout")
+        .arg("--target")
+        .arg(target)
+        .arg("--from-snapshot")
+        .arg(case.join("input.json"))
+        .arg("--config")
+        .arg(&config)
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "Generating {} for {} failed:\n{}",
+        name,
+        target,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn empty_config(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(format!("{}.toml", name));
+    fs::write(&path, "").unwrap();
+    path
+}
@@ -0,0 +1,63 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+package dk.undo.i18n
+
+sealed class Locale {
+    object Da : Locale()
+    object En : Locale()
+}
+
+sealed class Cardinality {
+    object Zero : Cardinality()
+    object One : Cardinality()
+    object Two : Cardinality()
+    object Few : Cardinality()
+    object Many : Cardinality()
+    object Other : Cardinality()
+
+    companion object {
+        fun of(locale: Locale, count: Int): Cardinality {
+            val n = Math.abs(count)
+            return when (locale) {
+                Locale.Da -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+                Locale.En -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+            }
+        }
+    }
+}
+
+object I18n {
+    object Undo {
+        // welcome_title
+        fun welcomeTitle(locale: Locale, name: String): String = when (locale) {
+            Locale.Da -> "Velkommen ${name}"
+            Locale.En -> "Welcome ${name}"
+        }
+
+        // claim_summary
+        fun claimSummary(locale: Locale, claims: Int, name: String): String = when (locale) {
+            Locale.Da -> "${name} har ${claims} skader"
+            Locale.En -> "${name} has ${claims} claims"
+        }
+
+        // terms
+        fun terms(locale: Locale): String = when (locale) {
+            Locale.Da -> "Linje 1\nLinje 2"
+            Locale.En -> "Line 1\nLine 2"
+        }
+    }
+
+    object Car {
+        // car_title
+        fun carTitle(locale: Locale): String = when (locale) {
+            Locale.Da -> "Bil"
+            Locale.En -> "Car"
+        }
+    }
+}
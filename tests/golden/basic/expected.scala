@@ -0,0 +1,113 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+}
+
+object I18n {
+  object undo {
+    // welcome_title
+    def welcomeTitle(name: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Velkommen ${name}"""
+        }
+        case Locale.En => {
+          s"""Welcome ${name}"""
+        }
+      }
+    }
+
+    // claim_summary
+    def claimSummary(claims: Int, name: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""${name} har ${claims} skader"""
+        }
+        case Locale.En => {
+          s"""${name} has ${claims} claims"""
+        }
+      }
+    }
+
+    // terms
+    def terms(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Linje 1
+Linje 2"""
+        }
+        case Locale.En => {
+          """Line 1
+Line 2"""
+        }
+      }
+    }
+  }
+
+  object car {
+    // car_title
+    def carTitle(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Bil"""
+        }
+        case Locale.En => {
+          """Car"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,73 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+public enum I18n {
+    public enum Locale {
+        case da
+        case en
+    }
+
+    public enum Cardinality {
+        case zero
+        case one
+        case two
+        case few
+        case many
+        case other
+
+        public static func of(_ count: Int, locale: Locale) -> Cardinality {
+            let n = abs(count)
+            switch locale {
+            case .da:
+                if n == 1 { return .one }
+                return .other
+            case .en:
+                if n == 1 { return .one }
+                return .other
+            }
+        }
+    }
+
+    public enum Undo {
+        // welcome_title
+        public static func welcomeTitle(locale: Locale, name: String) -> String {
+            switch locale {
+            case .da:
+                return "Velkommen \(name)"
+            case .en:
+                return "Welcome \(name)"
+            }
+        }
+
+        // claim_summary
+        public static func claimSummary(locale: Locale, claims: Int, name: String) -> String {
+            switch locale {
+            case .da:
+                return "\(name) har \(claims) skader"
+            case .en:
+                return "\(name) has \(claims) claims"
+            }
+        }
+
+        // terms
+        public static func terms(locale: Locale) -> String {
+            switch locale {
+            case .da:
+                return "Linje 1\nLinje 2"
+            case .en:
+                return "Line 1\nLine 2"
+            }
+        }
+    }
+
+    public enum Car {
+        // car_title
+        public static func carTitle(locale: Locale) -> String {
+            switch locale {
+            case .da:
+                return "Bil"
+            case .en:
+                return "Car"
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+/* eslint-disable */
+
+export type Locale = "da" | "en";
+
+export type Cardinality = "zero" | "one" | "two" | "few" | "many" | "other";
+
+export function cardinality(locale: Locale, count: number): Cardinality {
+  const n = Math.abs(count);
+  switch (locale) {
+    case "da":
+      if (n == 1) return "one";
+      return "other";
+    case "en":
+      if (n == 1) return "one";
+      return "other";
+  }
+}
+
+export const undo = {
+  // welcome_title
+  welcomeTitle(locale: Locale, name: string): string {
+    switch (locale) {
+      case "da":
+        return `Velkommen ${name}`;
+      case "en":
+        return `Welcome ${name}`;
+    }
+  },
+
+  // claim_summary
+  claimSummary(locale: Locale, claims: number, name: string): string {
+    switch (locale) {
+      case "da":
+        return `${name} har ${claims} skader`;
+      case "en":
+        return `${name} has ${claims} claims`;
+    }
+  },
+
+  // terms
+  terms(locale: Locale): string {
+    switch (locale) {
+      case "da":
+        return `Linje 1
+Linje 2`;
+      case "en":
+        return `Line 1
+Line 2`;
+    }
+  },
+};
+
+export const car = {
+  // car_title
+  carTitle(locale: Locale): string {
+    switch (locale) {
+      case "da":
+        return `Bil`;
+      case "en":
+        return `Car`;
+    }
+  },
+};
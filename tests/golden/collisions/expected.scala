@@ -0,0 +1,109 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+}
+
+object I18n {
+  object undo {
+    // policy_start
+    def policyStart3(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Policen starter"""
+        }
+        case Locale.En => {
+          """The policy starts"""
+        }
+      }
+    }
+
+    // policyStart
+    def policyStart(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Start"""
+        }
+        case Locale.En => {
+          """Start"""
+        }
+      }
+    }
+
+    // policy-start
+    def policyStart2(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Police start"""
+        }
+        case Locale.En => {
+          """Policy start"""
+        }
+      }
+    }
+
+    // greeting
+    def greeting(firstName: String, firstName2: String, locale2: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Hej ${firstName2} ${firstName} på ${locale2}"""
+        }
+        case Locale.En => {
+          s"""Hi ${firstName2} ${firstName} in ${locale2}"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,104 @@
+// format: off
+
+
+package com.example.translations
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.Sv => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+
+  object Sv extends Locale
+}
+
+object Texts {
+  object undo {
+    // welcome_title_ios
+    def welcomeTitleIos(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Velkommen"""
+        }
+        case Locale.En => {
+          """Welcome"""
+        }
+        case Locale.Sv => {
+          """Välkommen"""
+        }
+      }
+    }
+
+    // goodbye
+    def goodbye(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Farvel"""
+        }
+        case Locale.En => {
+          """Goodbye"""
+        }
+        case Locale.Sv => {
+          """Goodbye"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,89 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+package dk.undo.i18n
+
+sealed class Locale {
+    object Da : Locale()
+    object En : Locale()
+}
+
+sealed class Cardinality {
+    object Zero : Cardinality()
+    object One : Cardinality()
+    object Two : Cardinality()
+    object Few : Cardinality()
+    object Many : Cardinality()
+    object Other : Cardinality()
+
+    companion object {
+        fun of(locale: Locale, count: Int): Cardinality {
+            val n = Math.abs(count)
+            return when (locale) {
+                Locale.Da -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+                Locale.En -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+            }
+        }
+    }
+}
+
+data class Money(val amount: java.math.BigDecimal, val currency: java.util.Currency)
+
+object Format {
+    fun javaLocale(locale: Locale): java.util.Locale = when (locale) {
+        Locale.Da -> java.util.Locale.forLanguageTag("da")
+        Locale.En -> java.util.Locale.forLanguageTag("en")
+    }
+
+    fun number(locale: Locale, value: Double): String =
+        java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+
+    fun fixed(locale: Locale, value: Double, decimals: Int): String =
+        String.format(javaLocale(locale), "%,.${decimals}f", value)
+
+    fun decimal(locale: Locale, value: java.math.BigDecimal): String {
+        val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+        format.minimumFractionDigits = maxOf(value.scale(), 0)
+        format.maximumFractionDigits = maxOf(value.scale(), 0)
+        return format.format(value)
+    }
+
+    fun date(locale: Locale, value: java.time.LocalDate): String =
+        java.time.format.DateTimeFormatter
+            .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+            .withLocale(javaLocale(locale))
+            .format(value)
+
+    fun money(locale: Locale, value: Money): String {
+        val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+        format.currency = value.currency
+        return format.format(value.amount)
+    }
+}
+
+object I18n {
+    object Undo {
+        // policy.start
+        fun policy_start(locale: Locale, first_name: String, start_date: java.time.LocalDate): String = when (locale) {
+            Locale.Da -> "Hej ${first_name}, din police starter ${Format.date(locale, start_date)}"
+            Locale.En -> "Hi ${first_name}, your policy starts ${Format.date(locale, start_date)}"
+        }
+
+        // policy:start
+        fun policy_start2(locale: Locale): String = when (locale) {
+            Locale.Da -> "Start"
+            Locale.En -> "Start"
+        }
+
+        // 2fa.code
+        fun `2fa_code`(locale: Locale, code: String): String = when (locale) {
+            Locale.Da -> "Kode: ${code}"
+            Locale.En -> "Code: ${code}"
+        }
+    }
+}
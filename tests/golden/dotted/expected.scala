@@ -0,0 +1,140 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+}
+
+final case class Money(amount: BigDecimal, currency: java.util.Currency)
+
+object Format {
+  def javaLocale(locale: Locale): java.util.Locale = {
+    locale match {
+      case Locale.Da => {
+        java.util.Locale.forLanguageTag("""da""")
+      }
+      case Locale.En => {
+        java.util.Locale.forLanguageTag("""en""")
+      }
+    }
+  }
+
+  def number(value: Double)(implicit locale: Locale): String = {
+    java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+  }
+
+  def fixed(value: Double, decimals: Int)(implicit locale: Locale): String = {
+    s"%,.${decimals}f".formatLocal(javaLocale(locale), value)
+  }
+
+  def decimal(value: BigDecimal)(implicit locale: Locale): String = {
+    val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+    format.setMinimumFractionDigits(value.scale max 0)
+    format.setMaximumFractionDigits(value.scale max 0)
+    format.format(value.bigDecimal)
+  }
+
+  def date(value: java.time.LocalDate)(implicit locale: Locale): String = {
+    java.time.format.DateTimeFormatter
+      .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+      .withLocale(javaLocale(locale))
+      .format(value)
+  }
+
+  def money(value: Money)(implicit locale: Locale): String = {
+    val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+    format.setCurrency(value.currency)
+    format.format(value.amount.bigDecimal)
+  }
+}
+
+object I18n {
+  object undo {
+    // policy.start
+    def `policy.start`(`first.name`: String, `start.date`: java.time.LocalDate)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Hej ${`first.name`}, din police starter ${Format.date(`start.date`)}"""
+        }
+        case Locale.En => {
+          s"""Hi ${`first.name`}, your policy starts ${Format.date(`start.date`)}"""
+        }
+      }
+    }
+
+    // policy:start
+    def `policy:start`(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Start"""
+        }
+        case Locale.En => {
+          """Start"""
+        }
+      }
+    }
+
+    // 2fa.code
+    def `2fa.code`(code: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Kode: ${code}"""
+        }
+        case Locale.En => {
+          s"""Code: ${code}"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,121 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+import Foundation
+
+public enum I18n {
+    public enum Locale {
+        case da
+        case en
+    }
+
+    public enum Cardinality {
+        case zero
+        case one
+        case two
+        case few
+        case many
+        case other
+
+        public static func of(_ count: Int, locale: Locale) -> Cardinality {
+            let n = abs(count)
+            switch locale {
+            case .da:
+                if n == 1 { return .one }
+                return .other
+            case .en:
+                if n == 1 { return .one }
+                return .other
+            }
+        }
+    }
+
+    public struct Money {
+        public let amount: Decimal
+        public let currencyCode: String
+
+        public init(amount: Decimal, currencyCode: String) {
+            self.amount = amount
+            self.currencyCode = currencyCode
+        }
+    }
+
+    enum Format {
+        static func foundationLocale(_ locale: Locale) -> Foundation.Locale {
+            switch locale {
+            case .da: return Foundation.Locale(identifier: "da")
+            case .en: return Foundation.Locale(identifier: "en")
+            }
+        }
+
+        static func number(_ value: Double, decimals: Int? = nil, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            if let decimals = decimals {
+                formatter.minimumFractionDigits = decimals
+                formatter.maximumFractionDigits = decimals
+            }
+            return formatter.string(from: NSNumber(value: value)) ?? "\(value)"
+        }
+
+        static func decimal(_ value: Decimal, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            formatter.minimumFractionDigits = max(-value.exponent, 0)
+            formatter.maximumFractionDigits = max(-value.exponent, 0)
+            return formatter.string(from: value as NSDecimalNumber) ?? "\(value)"
+        }
+
+        /// Formatted in UTC, so pass dates as midnight UTC.
+        static func date(_ value: Date, locale: Locale) -> String {
+            let formatter = DateFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.timeZone = TimeZone(identifier: "UTC")
+            formatter.dateStyle = .long
+            formatter.timeStyle = .none
+            return formatter.string(from: value)
+        }
+
+        static func money(_ value: Money, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .currency
+            formatter.currencyCode = value.currencyCode
+            return formatter.string(from: value.amount as NSDecimalNumber)
+                ?? "\(value.amount) \(value.currencyCode)"
+        }
+    }
+
+    public enum Undo {
+        // policy.start
+        public static func policy_start(locale: Locale, first_name: String, start_date: Date) -> String {
+            switch locale {
+            case .da:
+                return "Hej \(first_name), din police starter \(Format.date(start_date, locale: locale))"
+            case .en:
+                return "Hi \(first_name), your policy starts \(Format.date(start_date, locale: locale))"
+            }
+        }
+
+        // policy:start
+        public static func policy_start2(locale: Locale) -> String {
+            switch locale {
+            case .da:
+                return "Start"
+            case .en:
+                return "Start"
+            }
+        }
+
+        // 2fa.code
+        public static func _2fa_code(locale: Locale, code: String) -> String {
+            switch locale {
+            case .da:
+                return "Kode: \(code)"
+            case .en:
+                return "Code: \(code)"
+            }
+        }
+    }
+}
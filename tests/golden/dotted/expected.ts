@@ -0,0 +1,82 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+/* eslint-disable */
+
+export type Locale = "da" | "en";
+
+export type Cardinality = "zero" | "one" | "two" | "few" | "many" | "other";
+
+export function cardinality(locale: Locale, count: number): Cardinality {
+  const n = Math.abs(count);
+  switch (locale) {
+    case "da":
+      if (n == 1) return "one";
+      return "other";
+    case "en":
+      if (n == 1) return "one";
+      return "other";
+  }
+}
+
+export type Money = { amount: number; currency: string };
+
+function formatNumber(locale: Locale, value: number, decimals?: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    minimumFractionDigits: decimals,
+    maximumFractionDigits: decimals,
+  }).format(value);
+}
+
+function formatDecimal(locale: Locale, value: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    maximumFractionDigits: 20,
+  }).format(value);
+}
+
+// Formatted in UTC, so pass dates as midnight UTC, like `new Date(Date.UTC(2021, 2, 1))`.
+function formatDate(locale: Locale, value: Date): string {
+  return new Intl.DateTimeFormat(locale.replace("_", "-"), {
+    year: "numeric",
+    month: "long",
+    day: "numeric",
+    timeZone: "UTC",
+  }).format(value);
+}
+
+function formatMoney(locale: Locale, value: Money): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    style: "currency",
+    currency: value.currency,
+  }).format(value.amount);
+}
+
+export const undo = {
+  // policy.start
+  policy_start(locale: Locale, first_name: string, start_date: Date): string {
+    switch (locale) {
+      case "da":
+        return `Hej ${first_name}, din police starter ${formatDate(locale, start_date)}`;
+      case "en":
+        return `Hi ${first_name}, your policy starts ${formatDate(locale, start_date)}`;
+    }
+  },
+
+  // policy:start
+  policy_start2(locale: Locale): string {
+    switch (locale) {
+      case "da":
+        return `Start`;
+      case "en":
+        return `Start`;
+    }
+  },
+
+  // 2fa.code
+  _2fa_code(locale: Locale, code: string): string {
+    switch (locale) {
+      case "da":
+        return `Kode: ${code}`;
+      case "en":
+        return `Code: ${code}`;
+    }
+  },
+};
@@ -0,0 +1,72 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+package dk.undo.i18n
+
+sealed class Locale {
+    object En : Locale()
+}
+
+sealed class Cardinality {
+    object Zero : Cardinality()
+    object One : Cardinality()
+    object Two : Cardinality()
+    object Few : Cardinality()
+    object Many : Cardinality()
+    object Other : Cardinality()
+
+    companion object {
+        fun of(locale: Locale, count: Int): Cardinality {
+            val n = Math.abs(count)
+            return when (locale) {
+                Locale.En -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+            }
+        }
+    }
+}
+
+object I18n {
+    object Undo {
+        // discount
+        fun discount(locale: Locale): String = when (locale) {
+            Locale.En -> "\$5 discount"
+        }
+
+        // discount_amount
+        fun discountAmount(locale: Locale, amount: Int): String = when (locale) {
+            Locale.En -> "Save \$${amount} now"
+        }
+
+        // quoted
+        fun quoted(locale: Locale): String = when (locale) {
+            Locale.En -> "He said \"\"\"hi\"\"\" to me"
+        }
+
+        // ends_with_quote
+        fun endsWithQuote(locale: Locale): String = when (locale) {
+            Locale.En -> "Say \"cheese\""
+        }
+
+        // path
+        fun path(locale: Locale, user: String): String = when (locale) {
+            Locale.En -> "C:\\Users\\${user}\\n"
+        }
+
+        // raw_backslash
+        fun rawBackslash(locale: Locale): String = when (locale) {
+            Locale.En -> "Use \\n for a new line"
+        }
+
+        // quote_before_placeholder
+        fun quoteBeforePlaceholder(locale: Locale, name: String): String = when (locale) {
+            Locale.En -> "\"${name}\""
+        }
+
+        // interpolation_lookalike
+        fun interpolationLookalike(locale: Locale): String = when (locale) {
+            Locale.En -> "Write \${name} or \$name"
+        }
+    }
+}
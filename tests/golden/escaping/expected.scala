@@ -0,0 +1,120 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object En extends Locale
+}
+
+object I18n {
+  object undo {
+    // discount
+    def discount(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          """$5 discount"""
+        }
+      }
+    }
+
+    // discount_amount
+    def discountAmount(amount: Int)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          s"""Save $$${amount} now"""
+        }
+      }
+    }
+
+    // quoted
+    def quoted(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          s"""He said ""${"\""}hi""${"\""} to me"""
+        }
+      }
+    }
+
+    // ends_with_quote
+    def endsWithQuote(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          s"""Say "cheese${"\""}"""
+        }
+      }
+    }
+
+    // path
+    def path(user: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          s"""C:\\Users\\${user}\\n"""
+        }
+      }
+    }
+
+    // raw_backslash
+    def rawBackslash(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          """Use \n for a new line"""
+        }
+      }
+    }
+
+    // quote_before_placeholder
+    def quoteBeforePlaceholder(name: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          s""""${name}${"\""}"""
+        }
+      }
+    }
+
+    // interpolation_lookalike
+    def interpolationLookalike(implicit locale: Locale): String = {
+      locale match {
+        case Locale.En => {
+          """Write ${name} or $name"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
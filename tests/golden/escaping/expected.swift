@@ -0,0 +1,91 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+public enum I18n {
+    public enum Locale {
+        case en
+    }
+
+    public enum Cardinality {
+        case zero
+        case one
+        case two
+        case few
+        case many
+        case other
+
+        public static func of(_ count: Int, locale: Locale) -> Cardinality {
+            let n = abs(count)
+            switch locale {
+            case .en:
+                if n == 1 { return .one }
+                return .other
+            }
+        }
+    }
+
+    public enum Undo {
+        // discount
+        public static func discount(locale: Locale) -> String {
+            switch locale {
+            case .en:
+                return "$5 discount"
+            }
+        }
+
+        // discount_amount
+        public static func discountAmount(locale: Locale, amount: Int) -> String {
+            switch locale {
+            case .en:
+                return "Save $\(amount) now"
+            }
+        }
+
+        // quoted
+        public static func quoted(locale: Locale) -> String {
+            switch locale {
+            case .en:
+                return "He said \"\"\"hi\"\"\" to me"
+            }
+        }
+
+        // ends_with_quote
+        public static func endsWithQuote(locale: Locale) -> String {
+            switch locale {
+            case .en:
+                return "Say \"cheese\""
+            }
+        }
+
+        // path
+        public static func path(locale: Locale, user: String) -> String {
+            switch locale {
+            case .en:
+                return "C:\\Users\\\(user)\\n"
+            }
+        }
+
+        // raw_backslash
+        public static func rawBackslash(locale: Locale) -> String {
+            switch locale {
+            case .en:
+                return "Use \\n for a new line"
+            }
+        }
+
+        // quote_before_placeholder
+        public static func quoteBeforePlaceholder(locale: Locale, name: String) -> String {
+            switch locale {
+            case .en:
+                return "\"\(name)\""
+            }
+        }
+
+        // interpolation_lookalike
+        public static func interpolationLookalike(locale: Locale) -> String {
+            switch locale {
+            case .en:
+                return "Write ${name} or $name"
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+/* eslint-disable */
+
+export type Locale = "en";
+
+export type Cardinality = "zero" | "one" | "two" | "few" | "many" | "other";
+
+export function cardinality(locale: Locale, count: number): Cardinality {
+  const n = Math.abs(count);
+  switch (locale) {
+    case "en":
+      if (n == 1) return "one";
+      return "other";
+  }
+}
+
+export const undo = {
+  // discount
+  discount(locale: Locale): string {
+    switch (locale) {
+      case "en":
+        return `$5 discount`;
+    }
+  },
+
+  // discount_amount
+  discountAmount(locale: Locale, amount: number): string {
+    switch (locale) {
+      case "en":
+        return `Save $${amount} now`;
+    }
+  },
+
+  // quoted
+  quoted(locale: Locale): string {
+    switch (locale) {
+      case "en":
+        return `He said """hi""" to me`;
+    }
+  },
+
+  // ends_with_quote
+  endsWithQuote(locale: Locale): string {
+    switch (locale) {
+      case "en":
+        return `Say "cheese"`;
+    }
+  },
+
+  // path
+  path(locale: Locale, user: string): string {
+    switch (locale) {
+      case "en":
+        return `C:\\Users\\${user}\\n`;
+    }
+  },
+
+  // raw_backslash
+  rawBackslash(locale: Locale): string {
+    switch (locale) {
+      case "en":
+        return `Use \\n for a new line`;
+    }
+  },
+
+  // quote_before_placeholder
+  quoteBeforePlaceholder(locale: Locale, name: string): string {
+    switch (locale) {
+      case "en":
+        return `"${name}"`;
+    }
+  },
+
+  // interpolation_lookalike
+  interpolationLookalike(locale: Locale): string {
+    switch (locale) {
+      case "en":
+        return `Write \${name} or $name`;
+    }
+  },
+};
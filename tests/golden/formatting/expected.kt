@@ -0,0 +1,107 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+package dk.undo.i18n
+
+sealed class Locale {
+    object Da : Locale()
+    object EnUs : Locale()
+}
+
+sealed class Cardinality {
+    object Zero : Cardinality()
+    object One : Cardinality()
+    object Two : Cardinality()
+    object Few : Cardinality()
+    object Many : Cardinality()
+    object Other : Cardinality()
+
+    companion object {
+        fun of(locale: Locale, count: Int): Cardinality {
+            val n = Math.abs(count)
+            return when (locale) {
+                Locale.Da -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+                Locale.EnUs -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+            }
+        }
+    }
+}
+
+data class Money(val amount: java.math.BigDecimal, val currency: java.util.Currency)
+
+object Format {
+    fun javaLocale(locale: Locale): java.util.Locale = when (locale) {
+        Locale.Da -> java.util.Locale.forLanguageTag("da")
+        Locale.EnUs -> java.util.Locale.forLanguageTag("en-US")
+    }
+
+    fun number(locale: Locale, value: Double): String =
+        java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+
+    fun fixed(locale: Locale, value: Double, decimals: Int): String =
+        String.format(javaLocale(locale), "%,.${decimals}f", value)
+
+    fun decimal(locale: Locale, value: java.math.BigDecimal): String {
+        val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+        format.minimumFractionDigits = maxOf(value.scale(), 0)
+        format.maximumFractionDigits = maxOf(value.scale(), 0)
+        return format.format(value)
+    }
+
+    fun date(locale: Locale, value: java.time.LocalDate): String =
+        java.time.format.DateTimeFormatter
+            .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+            .withLocale(javaLocale(locale))
+            .format(value)
+
+    fun money(locale: Locale, value: Money): String {
+        val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+        format.currency = value.currency
+        return format.format(value.amount)
+    }
+}
+
+object I18n {
+    object Undo {
+        // price
+        fun price(locale: Locale, price: Money): String = when (locale) {
+            Locale.Da -> "Pris: ${Format.money(locale, price)}"
+            Locale.EnUs -> "Price: ${Format.money(locale, price)}"
+        }
+
+        // start_date
+        fun startDate(locale: Locale, start: java.time.LocalDate): String = when (locale) {
+            Locale.Da -> "Starter ${Format.date(locale, start)}"
+            Locale.EnUs -> "Starts ${Format.date(locale, start)}"
+        }
+
+        // deductible
+        fun deductible(locale: Locale, amount: java.math.BigDecimal): String = when (locale) {
+            Locale.Da -> "Selvrisiko ${Format.decimal(locale, amount)} kr."
+            Locale.EnUs -> "Deductible DKK ${Format.decimal(locale, amount)}"
+        }
+
+        // distance
+        fun distance(locale: Locale, km: Double): String = when (locale) {
+            Locale.Da -> "${Format.fixed(locale, km, 1)} km"
+            Locale.EnUs -> "${Format.fixed(locale, km, 1)} km"
+        }
+
+        // rating
+        fun rating(locale: Locale, rating: Double): String = when (locale) {
+            Locale.Da -> "Bedømmelse ${Format.number(locale, rating)}"
+            Locale.EnUs -> "Rating ${Format.number(locale, rating)}"
+        }
+
+        // claims
+        fun claims(locale: Locale, count: Int): String = when (locale) {
+            Locale.Da -> "${count} skader"
+            Locale.EnUs -> "${count} claims"
+        }
+    }
+}
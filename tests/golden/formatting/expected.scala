@@ -0,0 +1,176 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.EnUs => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object EnUs extends Locale
+}
+
+final case class Money(amount: BigDecimal, currency: java.util.Currency)
+
+object Format {
+  def javaLocale(locale: Locale): java.util.Locale = {
+    locale match {
+      case Locale.Da => {
+        java.util.Locale.forLanguageTag("""da""")
+      }
+      case Locale.EnUs => {
+        java.util.Locale.forLanguageTag("""en-US""")
+      }
+    }
+  }
+
+  def number(value: Double)(implicit locale: Locale): String = {
+    java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+  }
+
+  def fixed(value: Double, decimals: Int)(implicit locale: Locale): String = {
+    s"%,.${decimals}f".formatLocal(javaLocale(locale), value)
+  }
+
+  def decimal(value: BigDecimal)(implicit locale: Locale): String = {
+    val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+    format.setMinimumFractionDigits(value.scale max 0)
+    format.setMaximumFractionDigits(value.scale max 0)
+    format.format(value.bigDecimal)
+  }
+
+  def date(value: java.time.LocalDate)(implicit locale: Locale): String = {
+    java.time.format.DateTimeFormatter
+      .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+      .withLocale(javaLocale(locale))
+      .format(value)
+  }
+
+  def money(value: Money)(implicit locale: Locale): String = {
+    val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+    format.setCurrency(value.currency)
+    format.format(value.amount.bigDecimal)
+  }
+}
+
+object I18n {
+  object undo {
+    // price
+    def price(price: Money)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Pris: ${Format.money(price)}"""
+        }
+        case Locale.EnUs => {
+          s"""Price: ${Format.money(price)}"""
+        }
+      }
+    }
+
+    // start_date
+    def startDate(start: java.time.LocalDate)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Starter ${Format.date(start)}"""
+        }
+        case Locale.EnUs => {
+          s"""Starts ${Format.date(start)}"""
+        }
+      }
+    }
+
+    // deductible
+    def deductible(amount: BigDecimal)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Selvrisiko ${Format.decimal(amount)} kr."""
+        }
+        case Locale.EnUs => {
+          s"""Deductible DKK ${Format.decimal(amount)}"""
+        }
+      }
+    }
+
+    // distance
+    def distance(km: Double)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""${Format.fixed(km, 1)} km"""
+        }
+        case Locale.EnUs => {
+          s"""${Format.fixed(km, 1)} km"""
+        }
+      }
+    }
+
+    // rating
+    def rating(rating: Double)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Bedømmelse ${Format.number(rating)}"""
+        }
+        case Locale.EnUs => {
+          s"""Rating ${Format.number(rating)}"""
+        }
+      }
+    }
+
+    // claims
+    def claims(count: Int)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""${count} skader"""
+        }
+        case Locale.EnUs => {
+          s"""${count} claims"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,151 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+import Foundation
+
+public enum I18n {
+    public enum Locale {
+        case da
+        case enUs
+    }
+
+    public enum Cardinality {
+        case zero
+        case one
+        case two
+        case few
+        case many
+        case other
+
+        public static func of(_ count: Int, locale: Locale) -> Cardinality {
+            let n = abs(count)
+            switch locale {
+            case .da:
+                if n == 1 { return .one }
+                return .other
+            case .enUs:
+                if n == 1 { return .one }
+                return .other
+            }
+        }
+    }
+
+    public struct Money {
+        public let amount: Decimal
+        public let currencyCode: String
+
+        public init(amount: Decimal, currencyCode: String) {
+            self.amount = amount
+            self.currencyCode = currencyCode
+        }
+    }
+
+    enum Format {
+        static func foundationLocale(_ locale: Locale) -> Foundation.Locale {
+            switch locale {
+            case .da: return Foundation.Locale(identifier: "da")
+            case .enUs: return Foundation.Locale(identifier: "en-US")
+            }
+        }
+
+        static func number(_ value: Double, decimals: Int? = nil, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            if let decimals = decimals {
+                formatter.minimumFractionDigits = decimals
+                formatter.maximumFractionDigits = decimals
+            }
+            return formatter.string(from: NSNumber(value: value)) ?? "\(value)"
+        }
+
+        static func decimal(_ value: Decimal, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            formatter.minimumFractionDigits = max(-value.exponent, 0)
+            formatter.maximumFractionDigits = max(-value.exponent, 0)
+            return formatter.string(from: value as NSDecimalNumber) ?? "\(value)"
+        }
+
+        /// Formatted in UTC, so pass dates as midnight UTC.
+        static func date(_ value: Date, locale: Locale) -> String {
+            let formatter = DateFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.timeZone = TimeZone(identifier: "UTC")
+            formatter.dateStyle = .long
+            formatter.timeStyle = .none
+            return formatter.string(from: value)
+        }
+
+        static func money(_ value: Money, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .currency
+            formatter.currencyCode = value.currencyCode
+            return formatter.string(from: value.amount as NSDecimalNumber)
+                ?? "\(value.amount) \(value.currencyCode)"
+        }
+    }
+
+    public enum Undo {
+        // price
+        public static func price(locale: Locale, price: Money) -> String {
+            switch locale {
+            case .da:
+                return "Pris: \(Format.money(price, locale: locale))"
+            case .enUs:
+                return "Price: \(Format.money(price, locale: locale))"
+            }
+        }
+
+        // start_date
+        public static func startDate(locale: Locale, start: Date) -> String {
+            switch locale {
+            case .da:
+                return "Starter \(Format.date(start, locale: locale))"
+            case .enUs:
+                return "Starts \(Format.date(start, locale: locale))"
+            }
+        }
+
+        // deductible
+        public static func deductible(locale: Locale, amount: Decimal) -> String {
+            switch locale {
+            case .da:
+                return "Selvrisiko \(Format.decimal(amount, locale: locale)) kr."
+            case .enUs:
+                return "Deductible DKK \(Format.decimal(amount, locale: locale))"
+            }
+        }
+
+        // distance
+        public static func distance(locale: Locale, km: Double) -> String {
+            switch locale {
+            case .da:
+                return "\(Format.number(km, decimals: 1, locale: locale)) km"
+            case .enUs:
+                return "\(Format.number(km, decimals: 1, locale: locale)) km"
+            }
+        }
+
+        // rating
+        public static func rating(locale: Locale, rating: Double) -> String {
+            switch locale {
+            case .da:
+                return "Bedømmelse \(Format.number(rating, locale: locale))"
+            case .enUs:
+                return "Rating \(Format.number(rating, locale: locale))"
+            }
+        }
+
+        // claims
+        public static func claims(locale: Locale, count: Int) -> String {
+            switch locale {
+            case .da:
+                return "\(count) skader"
+            case .enUs:
+                return "\(count) claims"
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+/* eslint-disable */
+
+export type Locale = "da" | "en_US";
+
+export type Cardinality = "zero" | "one" | "two" | "few" | "many" | "other";
+
+export function cardinality(locale: Locale, count: number): Cardinality {
+  const n = Math.abs(count);
+  switch (locale) {
+    case "da":
+      if (n == 1) return "one";
+      return "other";
+    case "en_US":
+      if (n == 1) return "one";
+      return "other";
+  }
+}
+
+export type Money = { amount: number; currency: string };
+
+function formatNumber(locale: Locale, value: number, decimals?: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    minimumFractionDigits: decimals,
+    maximumFractionDigits: decimals,
+  }).format(value);
+}
+
+function formatDecimal(locale: Locale, value: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    maximumFractionDigits: 20,
+  }).format(value);
+}
+
+// Formatted in UTC, so pass dates as midnight UTC, like `new Date(Date.UTC(2021, 2, 1))`.
+function formatDate(locale: Locale, value: Date): string {
+  return new Intl.DateTimeFormat(locale.replace("_", "-"), {
+    year: "numeric",
+    month: "long",
+    day: "numeric",
+    timeZone: "UTC",
+  }).format(value);
+}
+
+function formatMoney(locale: Locale, value: Money): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    style: "currency",
+    currency: value.currency,
+  }).format(value.amount);
+}
+
+export const undo = {
+  // price
+  price(locale: Locale, price: Money): string {
+    switch (locale) {
+      case "da":
+        return `Pris: ${formatMoney(locale, price)}`;
+      case "en_US":
+        return `Price: ${formatMoney(locale, price)}`;
+    }
+  },
+
+  // start_date
+  startDate(locale: Locale, start: Date): string {
+    switch (locale) {
+      case "da":
+        return `Starter ${formatDate(locale, start)}`;
+      case "en_US":
+        return `Starts ${formatDate(locale, start)}`;
+    }
+  },
+
+  // deductible
+  deductible(locale: Locale, amount: number): string {
+    switch (locale) {
+      case "da":
+        return `Selvrisiko ${formatDecimal(locale, amount)} kr.`;
+      case "en_US":
+        return `Deductible DKK ${formatDecimal(locale, amount)}`;
+    }
+  },
+
+  // distance
+  distance(locale: Locale, km: number): string {
+    switch (locale) {
+      case "da":
+        return `${formatNumber(locale, km, 1)} km`;
+      case "en_US":
+        return `${formatNumber(locale, km, 1)} km`;
+    }
+  },
+
+  // rating
+  rating(locale: Locale, rating: number): string {
+    switch (locale) {
+      case "da":
+        return `Bedømmelse ${formatNumber(locale, rating)}`;
+      case "en_US":
+        return `Rating ${formatNumber(locale, rating)}`;
+    }
+  },
+
+  // claims
+  claims(locale: Locale, count: number): string {
+    switch (locale) {
+      case "da":
+        return `${count} skader`;
+      case "en_US":
+        return `${count} claims`;
+    }
+  },
+};
@@ -0,0 +1,149 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+package dk.undo.i18n
+
+sealed class Locale {
+    object Da : Locale()
+    object En : Locale()
+}
+
+sealed class Cardinality {
+    object Zero : Cardinality()
+    object One : Cardinality()
+    object Two : Cardinality()
+    object Few : Cardinality()
+    object Many : Cardinality()
+    object Other : Cardinality()
+
+    companion object {
+        fun of(locale: Locale, count: Int): Cardinality {
+            val n = Math.abs(count)
+            return when (locale) {
+                Locale.Da -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+                Locale.En -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+            }
+        }
+    }
+}
+
+sealed class Gender {
+    object Female : Gender()
+    object Other : Gender()
+}
+
+sealed class HostGender {
+    object Female : HostGender()
+    object Male : HostGender()
+    object Other : HostGender()
+}
+
+data class Money(val amount: java.math.BigDecimal, val currency: java.util.Currency)
+
+object Format {
+    fun javaLocale(locale: Locale): java.util.Locale = when (locale) {
+        Locale.Da -> java.util.Locale.forLanguageTag("da")
+        Locale.En -> java.util.Locale.forLanguageTag("en")
+    }
+
+    fun number(locale: Locale, value: Double): String =
+        java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+
+    fun fixed(locale: Locale, value: Double, decimals: Int): String =
+        String.format(javaLocale(locale), "%,.${decimals}f", value)
+
+    fun decimal(locale: Locale, value: java.math.BigDecimal): String {
+        val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+        format.minimumFractionDigits = maxOf(value.scale(), 0)
+        format.maximumFractionDigits = maxOf(value.scale(), 0)
+        return format.format(value)
+    }
+
+    fun date(locale: Locale, value: java.time.LocalDate): String =
+        java.time.format.DateTimeFormatter
+            .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+            .withLocale(javaLocale(locale))
+            .format(value)
+
+    fun money(locale: Locale, value: Money): String {
+        val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+        format.currency = value.currency
+        return format.format(value.amount)
+    }
+}
+
+object I18n {
+    object Undo {
+        // inbox
+        fun inbox(locale: Locale, count: Int, sender: String): String = when (locale) {
+            Locale.Da -> when (count) {
+                0 -> "Du har ingen beskeder fra ${sender}."
+                else -> when (Cardinality.of(locale, count)) {
+                    else -> "Du har ${count} beskeder fra ${sender}."
+                }
+            }
+            Locale.En -> when (count) {
+                0 -> "You have no messages from ${sender}."
+                else -> when (Cardinality.of(locale, count)) {
+                    Cardinality.One -> "You have ${count} message from ${sender}."
+                    else -> "You have ${count} messages from ${sender}."
+                }
+            }
+        }
+
+        // party_invitation
+        fun partyInvitation(locale: Locale, host: String, hostGender: HostGender): String = when (locale) {
+            Locale.Da -> when (hostGender) {
+                HostGender.Female -> "${host} har inviteret dig til sin fest"
+                HostGender.Male -> "${host} har inviteret dig til sin fest"
+                else -> "${host} har inviteret dig"
+            }
+            Locale.En -> when (hostGender) {
+                HostGender.Female -> "${host} invited you to her party"
+                HostGender.Male -> "${host} invited you to his party"
+                else -> "${host} invited you to their party"
+            }
+        }
+
+        // rating
+        fun rating(locale: Locale, day: java.time.LocalDate, name: String, rating: Double): String = when (locale) {
+            Locale.Da -> "Bedømt ${Format.number(locale, rating)} den ${Format.date(locale, day)}. Gå ikke glip af ${name}!"
+            Locale.En -> "Rated ${Format.number(locale, rating)} on ${Format.date(locale, day)}. Don't miss it, it's {${name}} or {literally} great!"
+        }
+
+        // friends
+        fun friends(locale: Locale, count: Int, gender: Gender): String = when (locale) {
+            Locale.Da -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> when (gender) {
+                    Gender.Female -> "${count} veninde"
+                    else -> "${count} ven"
+                }
+                else -> "${count} venner"
+            }
+            Locale.En -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> when (gender) {
+                    Gender.Female -> "${count} friend of hers"
+                    else -> "${count} friend of theirs"
+                }
+                else -> "${count} friends"
+            }
+        }
+
+        // reviews
+        fun reviews(locale: Locale, count: Int, product: String): String = when (locale) {
+            Locale.Da -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${count} anmeldelse af ${product}"
+                else -> "${count} anmeldelser af ${product}"
+            }
+            Locale.En -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "One review of ${product}"
+                else -> "${count} reviews of ${product}"
+            }
+        }
+    }
+}
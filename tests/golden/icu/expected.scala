@@ -0,0 +1,281 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+}
+
+sealed trait Gender
+
+object Gender {
+  case object Female extends Gender
+
+  case object Other extends Gender
+}
+
+sealed trait HostGender
+
+object HostGender {
+  case object Female extends HostGender
+
+  case object Male extends HostGender
+
+  case object Other extends HostGender
+}
+
+final case class Money(amount: BigDecimal, currency: java.util.Currency)
+
+object Format {
+  def javaLocale(locale: Locale): java.util.Locale = {
+    locale match {
+      case Locale.Da => {
+        java.util.Locale.forLanguageTag("""da""")
+      }
+      case Locale.En => {
+        java.util.Locale.forLanguageTag("""en""")
+      }
+    }
+  }
+
+  def number(value: Double)(implicit locale: Locale): String = {
+    java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+  }
+
+  def fixed(value: Double, decimals: Int)(implicit locale: Locale): String = {
+    s"%,.${decimals}f".formatLocal(javaLocale(locale), value)
+  }
+
+  def decimal(value: BigDecimal)(implicit locale: Locale): String = {
+    val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+    format.setMinimumFractionDigits(value.scale max 0)
+    format.setMaximumFractionDigits(value.scale max 0)
+    format.format(value.bigDecimal)
+  }
+
+  def date(value: java.time.LocalDate)(implicit locale: Locale): String = {
+    java.time.format.DateTimeFormatter
+      .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+      .withLocale(javaLocale(locale))
+      .format(value)
+  }
+
+  def money(value: Money)(implicit locale: Locale): String = {
+    val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+    format.setCurrency(value.currency)
+    format.format(value.amount.bigDecimal)
+  }
+}
+
+object I18n {
+  object undo {
+    // inbox
+    def inbox(count: Int, sender: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          count match {
+            case 0 => {
+              s"""Du har ingen beskeder fra ${sender}."""
+            }
+            case _ => {
+              Cardinality(count) match {
+                case _ => {
+                  s"""Du har ${count} beskeder fra ${sender}."""
+                }
+              }
+
+            }
+          }
+
+        }
+        case Locale.En => {
+          count match {
+            case 0 => {
+              s"""You have no messages from ${sender}."""
+            }
+            case _ => {
+              Cardinality(count) match {
+                case Cardinality.One => {
+                  s"""You have ${count} message from ${sender}."""
+                }
+                case _ => {
+                  s"""You have ${count} messages from ${sender}."""
+                }
+              }
+
+            }
+          }
+
+        }
+      }
+    }
+
+    // party_invitation
+    def partyInvitation(host: String, hostGender: HostGender)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          hostGender match {
+            case HostGender.Female => {
+              s"""${host} har inviteret dig til sin fest"""
+            }
+            case HostGender.Male => {
+              s"""${host} har inviteret dig til sin fest"""
+            }
+            case _ => {
+              s"""${host} har inviteret dig"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          hostGender match {
+            case HostGender.Female => {
+              s"""${host} invited you to her party"""
+            }
+            case HostGender.Male => {
+              s"""${host} invited you to his party"""
+            }
+            case _ => {
+              s"""${host} invited you to their party"""
+            }
+          }
+
+        }
+      }
+    }
+
+    // rating
+    def rating(day: java.time.LocalDate, name: String, rating: Double)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Bedømt ${Format.number(rating)} den ${Format.date(day)}. Gå ikke glip af ${name}!"""
+        }
+        case Locale.En => {
+          s"""Rated ${Format.number(rating)} on ${Format.date(day)}. Don't miss it, it's {${name}} or {literally} great!"""
+        }
+      }
+    }
+
+    // friends
+    def friends(count: Int, gender: Gender)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              gender match {
+                case Gender.Female => {
+                  s"""${count} veninde"""
+                }
+                case _ => {
+                  s"""${count} ven"""
+                }
+              }
+
+            }
+            case _ => {
+              s"""${count} venner"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              gender match {
+                case Gender.Female => {
+                  s"""${count} friend of hers"""
+                }
+                case _ => {
+                  s"""${count} friend of theirs"""
+                }
+              }
+
+            }
+            case _ => {
+              s"""${count} friends"""
+            }
+          }
+
+        }
+      }
+    }
+
+    // reviews
+    def reviews(count: Int, product: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${count} anmeldelse af ${product}"""
+            }
+            case _ => {
+              s"""${count} anmeldelser af ${product}"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""One review of ${product}"""
+            }
+            case _ => {
+              s"""${count} reviews of ${product}"""
+            }
+          }
+
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,214 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+import Foundation
+
+public enum I18n {
+    public enum Locale {
+        case da
+        case en
+    }
+
+    public enum Cardinality {
+        case zero
+        case one
+        case two
+        case few
+        case many
+        case other
+
+        public static func of(_ count: Int, locale: Locale) -> Cardinality {
+            let n = abs(count)
+            switch locale {
+            case .da:
+                if n == 1 { return .one }
+                return .other
+            case .en:
+                if n == 1 { return .one }
+                return .other
+            }
+        }
+    }
+
+    public enum Gender {
+        case female
+        case other
+    }
+
+    public enum HostGender {
+        case female
+        case male
+        case other
+    }
+
+    public struct Money {
+        public let amount: Decimal
+        public let currencyCode: String
+
+        public init(amount: Decimal, currencyCode: String) {
+            self.amount = amount
+            self.currencyCode = currencyCode
+        }
+    }
+
+    enum Format {
+        static func foundationLocale(_ locale: Locale) -> Foundation.Locale {
+            switch locale {
+            case .da: return Foundation.Locale(identifier: "da")
+            case .en: return Foundation.Locale(identifier: "en")
+            }
+        }
+
+        static func number(_ value: Double, decimals: Int? = nil, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            if let decimals = decimals {
+                formatter.minimumFractionDigits = decimals
+                formatter.maximumFractionDigits = decimals
+            }
+            return formatter.string(from: NSNumber(value: value)) ?? "\(value)"
+        }
+
+        static func decimal(_ value: Decimal, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .decimal
+            formatter.minimumFractionDigits = max(-value.exponent, 0)
+            formatter.maximumFractionDigits = max(-value.exponent, 0)
+            return formatter.string(from: value as NSDecimalNumber) ?? "\(value)"
+        }
+
+        /// Formatted in UTC, so pass dates as midnight UTC.
+        static func date(_ value: Date, locale: Locale) -> String {
+            let formatter = DateFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.timeZone = TimeZone(identifier: "UTC")
+            formatter.dateStyle = .long
+            formatter.timeStyle = .none
+            return formatter.string(from: value)
+        }
+
+        static func money(_ value: Money, locale: Locale) -> String {
+            let formatter = NumberFormatter()
+            formatter.locale = foundationLocale(locale)
+            formatter.numberStyle = .currency
+            formatter.currencyCode = value.currencyCode
+            return formatter.string(from: value.amount as NSDecimalNumber)
+                ?? "\(value.amount) \(value.currencyCode)"
+        }
+    }
+
+    public enum Undo {
+        // inbox
+        public static func inbox(locale: Locale, count: Int, sender: String) -> String {
+            switch locale {
+            case .da:
+                switch count {
+                case 0:
+                    return "Du har ingen beskeder fra \(sender)."
+                default:
+                    switch Cardinality.of(count, locale: locale) {
+                    default:
+                        return "Du har \(count) beskeder fra \(sender)."
+                    }
+                }
+            case .en:
+                switch count {
+                case 0:
+                    return "You have no messages from \(sender)."
+                default:
+                    switch Cardinality.of(count, locale: locale) {
+                    case .one:
+                        return "You have \(count) message from \(sender)."
+                    default:
+                        return "You have \(count) messages from \(sender)."
+                    }
+                }
+            }
+        }
+
+        // party_invitation
+        public static func partyInvitation(locale: Locale, host: String, hostGender: HostGender) -> String {
+            switch locale {
+            case .da:
+                switch hostGender {
+                case .female:
+                    return "\(host) har inviteret dig til sin fest"
+                case .male:
+                    return "\(host) har inviteret dig til sin fest"
+                default:
+                    return "\(host) har inviteret dig"
+                }
+            case .en:
+                switch hostGender {
+                case .female:
+                    return "\(host) invited you to her party"
+                case .male:
+                    return "\(host) invited you to his party"
+                default:
+                    return "\(host) invited you to their party"
+                }
+            }
+        }
+
+        // rating
+        public static func rating(locale: Locale, day: Date, name: String, rating: Double) -> String {
+            switch locale {
+            case .da:
+                return "Bedømt \(Format.number(rating, locale: locale)) den \(Format.date(day, locale: locale)). Gå ikke glip af \(name)!"
+            case .en:
+                return "Rated \(Format.number(rating, locale: locale)) on \(Format.date(day, locale: locale)). Don't miss it, it's {\(name)} or {literally} great!"
+            }
+        }
+
+        // friends
+        public static func friends(locale: Locale, count: Int, gender: Gender) -> String {
+            switch locale {
+            case .da:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    switch gender {
+                    case .female:
+                        return "\(count) veninde"
+                    default:
+                        return "\(count) ven"
+                    }
+                default:
+                    return "\(count) venner"
+                }
+            case .en:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    switch gender {
+                    case .female:
+                        return "\(count) friend of hers"
+                    default:
+                        return "\(count) friend of theirs"
+                    }
+                default:
+                    return "\(count) friends"
+                }
+            }
+        }
+
+        // reviews
+        public static func reviews(locale: Locale, count: Int, product: String) -> String {
+            switch locale {
+            case .da:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(count) anmeldelse af \(product)"
+                default:
+                    return "\(count) anmeldelser af \(product)"
+                }
+            case .en:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "One review of \(product)"
+                default:
+                    return "\(count) reviews of \(product)"
+                }
+            }
+        }
+    }
+}
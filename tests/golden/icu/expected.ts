@@ -0,0 +1,168 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+/* eslint-disable */
+
+export type Locale = "da" | "en";
+
+export type Cardinality = "zero" | "one" | "two" | "few" | "many" | "other";
+
+export function cardinality(locale: Locale, count: number): Cardinality {
+  const n = Math.abs(count);
+  switch (locale) {
+    case "da":
+      if (n == 1) return "one";
+      return "other";
+    case "en":
+      if (n == 1) return "one";
+      return "other";
+  }
+}
+
+export type Gender = "female" | "other";
+
+export type HostGender = "female" | "male" | "other";
+
+export type Money = { amount: number; currency: string };
+
+function formatNumber(locale: Locale, value: number, decimals?: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    minimumFractionDigits: decimals,
+    maximumFractionDigits: decimals,
+  }).format(value);
+}
+
+function formatDecimal(locale: Locale, value: number): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    maximumFractionDigits: 20,
+  }).format(value);
+}
+
+// Formatted in UTC, so pass dates as midnight UTC, like `new Date(Date.UTC(2021, 2, 1))`.
+function formatDate(locale: Locale, value: Date): string {
+  return new Intl.DateTimeFormat(locale.replace("_", "-"), {
+    year: "numeric",
+    month: "long",
+    day: "numeric",
+    timeZone: "UTC",
+  }).format(value);
+}
+
+function formatMoney(locale: Locale, value: Money): string {
+  return new Intl.NumberFormat(locale.replace("_", "-"), {
+    style: "currency",
+    currency: value.currency,
+  }).format(value.amount);
+}
+
+export const undo = {
+  // inbox
+  inbox(locale: Locale, count: number, sender: string): string {
+    switch (locale) {
+      case "da":
+        switch (count) {
+          case 0:
+            return `Du har ingen beskeder fra ${sender}.`;
+          default:
+            switch (cardinality(locale, count)) {
+              default:
+                return `Du har ${count} beskeder fra ${sender}.`;
+            }
+        }
+      case "en":
+        switch (count) {
+          case 0:
+            return `You have no messages from ${sender}.`;
+          default:
+            switch (cardinality(locale, count)) {
+              case "one":
+                return `You have ${count} message from ${sender}.`;
+              default:
+                return `You have ${count} messages from ${sender}.`;
+            }
+        }
+    }
+  },
+
+  // party_invitation
+  partyInvitation(locale: Locale, host: string, hostGender: HostGender): string {
+    switch (locale) {
+      case "da":
+        switch (hostGender) {
+          case "female":
+            return `${host} har inviteret dig til sin fest`;
+          case "male":
+            return `${host} har inviteret dig til sin fest`;
+          default:
+            return `${host} har inviteret dig`;
+        }
+      case "en":
+        switch (hostGender) {
+          case "female":
+            return `${host} invited you to her party`;
+          case "male":
+            return `${host} invited you to his party`;
+          default:
+            return `${host} invited you to their party`;
+        }
+    }
+  },
+
+  // rating
+  rating(locale: Locale, day: Date, name: string, rating: number): string {
+    switch (locale) {
+      case "da":
+        return `Bedømt ${formatNumber(locale, rating)} den ${formatDate(locale, day)}. Gå ikke glip af ${name}!`;
+      case "en":
+        return `Rated ${formatNumber(locale, rating)} on ${formatDate(locale, day)}. Don't miss it, it's {${name}} or {literally} great!`;
+    }
+  },
+
+  // friends
+  friends(locale: Locale, count: number, gender: Gender): string {
+    switch (locale) {
+      case "da":
+        switch (cardinality(locale, count)) {
+          case "one":
+            switch (gender) {
+              case "female":
+                return `${count} veninde`;
+              default:
+                return `${count} ven`;
+            }
+          default:
+            return `${count} venner`;
+        }
+      case "en":
+        switch (cardinality(locale, count)) {
+          case "one":
+            switch (gender) {
+              case "female":
+                return `${count} friend of hers`;
+              default:
+                return `${count} friend of theirs`;
+            }
+          default:
+            return `${count} friends`;
+        }
+    }
+  },
+
+  // reviews
+  reviews(locale: Locale, count: number, product: string): string {
+    switch (locale) {
+      case "da":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${count} anmeldelse af ${product}`;
+          default:
+            return `${count} anmeldelser af ${product}`;
+        }
+      case "en":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `One review of ${product}`;
+          default:
+            return `${count} reviews of ${product}`;
+        }
+    }
+  },
+};
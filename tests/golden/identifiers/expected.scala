@@ -0,0 +1,145 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+}
+
+object I18n {
+  object undo {
+    // given
+    def `given`(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Givet"""
+        }
+        case Locale.En => {
+          """Given"""
+        }
+      }
+    }
+
+    // then
+    def `then`(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Så"""
+        }
+        case Locale.En => {
+          """Then"""
+        }
+      }
+    }
+
+    // enum
+    def `enum`(`using`: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Enum ${`using`}"""
+        }
+        case Locale.En => {
+          s"""Enum ${`using`}"""
+        }
+      }
+    }
+
+    // export
+    def `export`(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Eksport"""
+        }
+        case Locale.En => {
+          """Export"""
+        }
+      }
+    }
+
+    // søknad_start
+    def søknadStart(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Ansøgning"""
+        }
+        case Locale.En => {
+          """Application"""
+        }
+      }
+    }
+
+    // policy.start
+    def `policy.start`(`end`: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Police ${`end`}"""
+        }
+        case Locale.En => {
+          s"""Policy ${`end`}"""
+        }
+      }
+    }
+
+    // total_
+    def total(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Total"""
+        }
+        case Locale.En => {
+          """Total"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,97 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Da extends Locale
+
+  object En extends Locale
+}
+
+object I18n {
+  object undo {
+    // type
+    def `type`(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Type"""
+        }
+        case Locale.En => {
+          """Type"""
+        }
+      }
+    }
+
+    // class
+    def `class`(`val`: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Klasse ${`val`}"""
+        }
+        case Locale.En => {
+          s"""Class ${`val`}"""
+        }
+      }
+    }
+
+    // 1st_place
+    def `1stPlace`(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          """Førsteplads"""
+        }
+        case Locale.En => {
+          """First place"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
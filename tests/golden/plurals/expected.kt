@@ -0,0 +1,99 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+package dk.undo.i18n
+
+sealed class Locale {
+    object Ar : Locale()
+    object Da : Locale()
+    object En : Locale()
+    object Pl : Locale()
+}
+
+sealed class Cardinality {
+    object Zero : Cardinality()
+    object One : Cardinality()
+    object Two : Cardinality()
+    object Few : Cardinality()
+    object Many : Cardinality()
+    object Other : Cardinality()
+
+    companion object {
+        fun of(locale: Locale, count: Int): Cardinality {
+            val n = Math.abs(count)
+            return when (locale) {
+                Locale.Ar -> when {
+                    n == 0 -> Zero
+                    n == 1 -> One
+                    n == 2 -> Two
+                    n % 100 >= 3 && n % 100 <= 10 -> Few
+                    n % 100 >= 11 && n % 100 <= 99 -> Many
+                    else -> Other
+                }
+                Locale.Da -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+                Locale.En -> when {
+                    n == 1 -> One
+                    else -> Other
+                }
+                Locale.Pl -> when {
+                    n == 1 -> One
+                    n % 10 >= 2 && n % 10 <= 4 && (n % 100 < 12 || n % 100 > 14) -> Few
+                    (n % 10 >= 0 && n % 10 <= 1) || (n % 10 >= 5 && n % 10 <= 9) || (n % 100 >= 12 && n % 100 <= 14) -> Many
+                    else -> Other
+                }
+            }
+        }
+    }
+}
+
+object I18n {
+    object Undo {
+        // items
+        fun items(locale: Locale, count: Int): String = when (locale) {
+            Locale.Ar -> when (Cardinality.of(locale, count)) {
+                Cardinality.Zero -> "لا عناصر"
+                Cardinality.One -> "عنصر واحد"
+                Cardinality.Two -> "عنصران"
+                Cardinality.Few -> "${count} عناصر"
+                Cardinality.Many -> "${count} عنصرًا"
+                else -> "${count} عنصر"
+            }
+            Locale.Da -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${count} ting"
+                else -> "${count} ting"
+            }
+            Locale.En -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "One item"
+                else -> "${count} items"
+            }
+            Locale.Pl -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${count} rzecz"
+                Cardinality.Few -> "${count} rzeczy"
+                Cardinality.Many -> "${count} rzeczy"
+                else -> "${count} rzeczy"
+            }
+        }
+
+        // cars_owned
+        fun carsOwned(locale: Locale, count: Int, name: String): String = when (locale) {
+            Locale.Ar -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${name} لديه سيارة"
+                else -> "${name} لديه ${count} سيارات"
+            }
+            Locale.Da -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${name} har en bil"
+                else -> "${name} har ${count} biler"
+            }
+            Locale.En -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${name} has a car"
+                else -> "${name} has ${count} cars"
+            }
+            Locale.Pl -> when (Cardinality.of(locale, count)) {
+                Cardinality.One -> "${name} ma samochód"
+                else -> "${name} ma ${count} samochodów"
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// format: off
+
+
+package dk.undo.i18n
+
+sealed trait Cardinality
+
+object Cardinality {
+  case object Zero extends Cardinality
+
+  case object One extends Cardinality
+
+  case object Two extends Cardinality
+
+  case object Few extends Cardinality
+
+  case object Many extends Cardinality
+
+  case object Other extends Cardinality
+
+  def apply(count: Int)(implicit locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Ar => {
+        math.abs(count) match {
+          case n if n == 0 => {
+            Zero
+          }
+          case n if n == 1 => {
+            One
+          }
+          case n if n == 2 => {
+            Two
+          }
+          case n if n % 100 >= 3 && n % 100 <= 10 => {
+            Few
+          }
+          case n if n % 100 >= 11 && n % 100 <= 99 => {
+            Many
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.Pl => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case n if n % 10 >= 2 && n % 10 <= 4 && (n % 100 < 12 || n % 100 > 14) => {
+            Few
+          }
+          case n if (n % 10 >= 0 && n % 10 <= 1) || (n % 10 >= 5 && n % 10 <= 9) || (n % 100 >= 12 && n % 100 <= 14) => {
+            Many
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+sealed trait Locale
+
+object Locale {
+  object Ar extends Locale
+
+  object Da extends Locale
+
+  object En extends Locale
+
+  object Pl extends Locale
+}
+
+object I18n {
+  object undo {
+    // items
+    def items(count: Int)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Ar => {
+          Cardinality(count) match {
+            case Cardinality.Zero => {
+              s"""لا عناصر"""
+            }
+            case Cardinality.One => {
+              s"""عنصر واحد"""
+            }
+            case Cardinality.Two => {
+              s"""عنصران"""
+            }
+            case Cardinality.Few => {
+              s"""${count} عناصر"""
+            }
+            case Cardinality.Many => {
+              s"""${count} عنصرًا"""
+            }
+            case _ => {
+              s"""${count} عنصر"""
+            }
+          }
+
+        }
+        case Locale.Da => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${count} ting"""
+            }
+            case _ => {
+              s"""${count} ting"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""One item"""
+            }
+            case _ => {
+              s"""${count} items"""
+            }
+          }
+
+        }
+        case Locale.Pl => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${count} rzecz"""
+            }
+            case Cardinality.Few => {
+              s"""${count} rzeczy"""
+            }
+            case Cardinality.Many => {
+              s"""${count} rzeczy"""
+            }
+            case _ => {
+              s"""${count} rzeczy"""
+            }
+          }
+
+        }
+      }
+    }
+
+    // cars_owned
+    def carsOwned(count: Int, name: String)(implicit locale: Locale): String = {
+      locale match {
+        case Locale.Ar => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${name} لديه سيارة"""
+            }
+            case _ => {
+              s"""${name} لديه ${count} سيارات"""
+            }
+          }
+
+        }
+        case Locale.Da => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${name} har en bil"""
+            }
+            case _ => {
+              s"""${name} har ${count} biler"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${name} has a car"""
+            }
+            case _ => {
+              s"""${name} has ${count} cars"""
+            }
+          }
+
+        }
+        case Locale.Pl => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${name} ma samochód"""
+            }
+            case _ => {
+              s"""${name} ma ${count} samochodów"""
+            }
+          }
+
+        }
+      }
+    }
+  }
+}
+
+// format: on
@@ -0,0 +1,125 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+
+public enum I18n {
+    public enum Locale {
+        case ar
+        case da
+        case en
+        case pl
+    }
+
+    public enum Cardinality {
+        case zero
+        case one
+        case two
+        case few
+        case many
+        case other
+
+        public static func of(_ count: Int, locale: Locale) -> Cardinality {
+            let n = abs(count)
+            switch locale {
+            case .ar:
+                if n == 0 { return .zero }
+                if n == 1 { return .one }
+                if n == 2 { return .two }
+                if n % 100 >= 3 && n % 100 <= 10 { return .few }
+                if n % 100 >= 11 && n % 100 <= 99 { return .many }
+                return .other
+            case .da:
+                if n == 1 { return .one }
+                return .other
+            case .en:
+                if n == 1 { return .one }
+                return .other
+            case .pl:
+                if n == 1 { return .one }
+                if n % 10 >= 2 && n % 10 <= 4 && (n % 100 < 12 || n % 100 > 14) { return .few }
+                if (n % 10 >= 0 && n % 10 <= 1) || (n % 10 >= 5 && n % 10 <= 9) || (n % 100 >= 12 && n % 100 <= 14) { return .many }
+                return .other
+            }
+        }
+    }
+
+    public enum Undo {
+        // items
+        public static func items(locale: Locale, count: Int) -> String {
+            switch locale {
+            case .ar:
+                switch Cardinality.of(count, locale: locale) {
+                case .zero:
+                    return "لا عناصر"
+                case .one:
+                    return "عنصر واحد"
+                case .two:
+                    return "عنصران"
+                case .few:
+                    return "\(count) عناصر"
+                case .many:
+                    return "\(count) عنصرًا"
+                default:
+                    return "\(count) عنصر"
+                }
+            case .da:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(count) ting"
+                default:
+                    return "\(count) ting"
+                }
+            case .en:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "One item"
+                default:
+                    return "\(count) items"
+                }
+            case .pl:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(count) rzecz"
+                case .few:
+                    return "\(count) rzeczy"
+                case .many:
+                    return "\(count) rzeczy"
+                default:
+                    return "\(count) rzeczy"
+                }
+            }
+        }
+
+        // cars_owned
+        public static func carsOwned(locale: Locale, count: Int, name: String) -> String {
+            switch locale {
+            case .ar:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(name) لديه سيارة"
+                default:
+                    return "\(name) لديه \(count) سيارات"
+                }
+            case .da:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(name) har en bil"
+                default:
+                    return "\(name) har \(count) biler"
+                }
+            case .en:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(name) has a car"
+                default:
+                    return "\(name) has \(count) cars"
+                }
+            case .pl:
+                switch Cardinality.of(count, locale: locale) {
+                case .one:
+                    return "\(name) ma samochód"
+                default:
+                    return "\(name) ma \(count) samochodów"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// This file is generated by i18n-code-gen. Don't edit it by hand.
+/* eslint-disable */
+
+export type Locale = "ar" | "da" | "en" | "pl";
+
+export type Cardinality = "zero" | "one" | "two" | "few" | "many" | "other";
+
+export function cardinality(locale: Locale, count: number): Cardinality {
+  const n = Math.abs(count);
+  switch (locale) {
+    case "ar":
+      if (n == 0) return "zero";
+      if (n == 1) return "one";
+      if (n == 2) return "two";
+      if (n % 100 >= 3 && n % 100 <= 10) return "few";
+      if (n % 100 >= 11 && n % 100 <= 99) return "many";
+      return "other";
+    case "da":
+      if (n == 1) return "one";
+      return "other";
+    case "en":
+      if (n == 1) return "one";
+      return "other";
+    case "pl":
+      if (n == 1) return "one";
+      if (n % 10 >= 2 && n % 10 <= 4 && (n % 100 < 12 || n % 100 > 14)) return "few";
+      if ((n % 10 >= 0 && n % 10 <= 1) || (n % 10 >= 5 && n % 10 <= 9) || (n % 100 >= 12 && n % 100 <= 14)) return "many";
+      return "other";
+  }
+}
+
+export const undo = {
+  // items
+  items(locale: Locale, count: number): string {
+    switch (locale) {
+      case "ar":
+        switch (cardinality(locale, count)) {
+          case "zero":
+            return `لا عناصر`;
+          case "one":
+            return `عنصر واحد`;
+          case "two":
+            return `عنصران`;
+          case "few":
+            return `${count} عناصر`;
+          case "many":
+            return `${count} عنصرًا`;
+          default:
+            return `${count} عنصر`;
+        }
+      case "da":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${count} ting`;
+          default:
+            return `${count} ting`;
+        }
+      case "en":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `One item`;
+          default:
+            return `${count} items`;
+        }
+      case "pl":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${count} rzecz`;
+          case "few":
+            return `${count} rzeczy`;
+          case "many":
+            return `${count} rzeczy`;
+          default:
+            return `${count} rzeczy`;
+        }
+    }
+  },
+
+  // cars_owned
+  carsOwned(locale: Locale, count: number, name: string): string {
+    switch (locale) {
+      case "ar":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${name} لديه سيارة`;
+          default:
+            return `${name} لديه ${count} سيارات`;
+        }
+      case "da":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${name} har en bil`;
+          default:
+            return `${name} har ${count} biler`;
+        }
+      case "en":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${name} has a car`;
+          default:
+            return `${name} has ${count} cars`;
+        }
+      case "pl":
+        switch (cardinality(locale, count)) {
+          case "one":
+            return `${name} ma samochód`;
+          default:
+            return `${name} ma ${count} samochodów`;
+        }
+    }
+  },
+};
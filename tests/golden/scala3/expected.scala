@@ -0,0 +1,278 @@
+// format: off
+
+
+package dk.undo.i18n
+
+enum Cardinality {
+  case Zero
+  case One
+  case Two
+  case Few
+  case Many
+  case Other
+}
+
+object Cardinality {
+  def apply(count: Int)(using locale: Locale): Cardinality = {
+    locale match {
+      case Locale.Da => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+      case Locale.En => {
+        math.abs(count) match {
+          case n if n == 1 => {
+            One
+          }
+          case _ => {
+            Other
+          }
+        }
+
+      }
+    }
+  }
+}
+
+enum Locale {
+  case Da
+  case En
+}
+
+enum Gender {
+  case Female
+  case Other
+}
+
+enum HostGender {
+  case Female
+  case Male
+  case Other
+}
+
+final case class Money(amount: BigDecimal, currency: java.util.Currency)
+
+object Format {
+  def javaLocale(locale: Locale): java.util.Locale = {
+    locale match {
+      case Locale.Da => {
+        java.util.Locale.forLanguageTag("""da""")
+      }
+      case Locale.En => {
+        java.util.Locale.forLanguageTag("""en""")
+      }
+    }
+  }
+
+  def number(value: Double)(using locale: Locale): String = {
+    java.text.NumberFormat.getNumberInstance(javaLocale(locale)).format(value)
+  }
+
+  def fixed(value: Double, decimals: Int)(using locale: Locale): String = {
+    s"%,.${decimals}f".formatLocal(javaLocale(locale), value)
+  }
+
+  def decimal(value: BigDecimal)(using locale: Locale): String = {
+    val format = java.text.NumberFormat.getNumberInstance(javaLocale(locale))
+    format.setMinimumFractionDigits(value.scale max 0)
+    format.setMaximumFractionDigits(value.scale max 0)
+    format.format(value.bigDecimal)
+  }
+
+  def date(value: java.time.LocalDate)(using locale: Locale): String = {
+    java.time.format.DateTimeFormatter
+      .ofLocalizedDate(java.time.format.FormatStyle.LONG)
+      .withLocale(javaLocale(locale))
+      .format(value)
+  }
+
+  def money(value: Money)(using locale: Locale): String = {
+    val format = java.text.NumberFormat.getCurrencyInstance(javaLocale(locale))
+    format.setCurrency(value.currency)
+    format.format(value.amount.bigDecimal)
+  }
+}
+
+object I18n {
+  object undo {
+    // inbox
+    def inbox(count: Int, sender: String)(using locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          count match {
+            case 0 => {
+              s"""Du har ingen beskeder fra ${sender}."""
+            }
+            case _ => {
+              Cardinality(count) match {
+                case _ => {
+                  s"""Du har ${count} beskeder fra ${sender}."""
+                }
+              }
+
+            }
+          }
+
+        }
+        case Locale.En => {
+          count match {
+            case 0 => {
+              s"""You have no messages from ${sender}."""
+            }
+            case _ => {
+              Cardinality(count) match {
+                case Cardinality.One => {
+                  s"""You have ${count} message from ${sender}."""
+                }
+                case _ => {
+                  s"""You have ${count} messages from ${sender}."""
+                }
+              }
+
+            }
+          }
+
+        }
+      }
+    }
+
+    // party_invitation
+    def partyInvitation(host: String, hostGender: HostGender)(using locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          hostGender match {
+            case HostGender.Female => {
+              s"""${host} har inviteret dig til sin fest"""
+            }
+            case HostGender.Male => {
+              s"""${host} har inviteret dig til sin fest"""
+            }
+            case _ => {
+              s"""${host} har inviteret dig"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          hostGender match {
+            case HostGender.Female => {
+              s"""${host} invited you to her party"""
+            }
+            case HostGender.Male => {
+              s"""${host} invited you to his party"""
+            }
+            case _ => {
+              s"""${host} invited you to their party"""
+            }
+          }
+
+        }
+      }
+    }
+
+    // rating
+    def rating(day: java.time.LocalDate, name: String, rating: Double)(using locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Bedømt ${Format.number(rating)} den ${Format.date(day)}. Gå ikke glip af ${name}!"""
+        }
+        case Locale.En => {
+          s"""Rated ${Format.number(rating)} on ${Format.date(day)}. Don't miss it, it's {${name}} or {literally} great!"""
+        }
+      }
+    }
+
+    // friends
+    def friends(count: Int, gender: Gender)(using locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              gender match {
+                case Gender.Female => {
+                  s"""${count} veninde"""
+                }
+                case _ => {
+                  s"""${count} ven"""
+                }
+              }
+
+            }
+            case _ => {
+              s"""${count} venner"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              gender match {
+                case Gender.Female => {
+                  s"""${count} friend of hers"""
+                }
+                case _ => {
+                  s"""${count} friend of theirs"""
+                }
+              }
+
+            }
+            case _ => {
+              s"""${count} friends"""
+            }
+          }
+
+        }
+      }
+    }
+
+    // reviews
+    def reviews(count: Int, product: String)(using locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""${count} anmeldelse af ${product}"""
+            }
+            case _ => {
+              s"""${count} anmeldelser af ${product}"""
+            }
+          }
+
+        }
+        case Locale.En => {
+          Cardinality(count) match {
+            case Cardinality.One => {
+              s"""One review of ${product}"""
+            }
+            case _ => {
+              s"""${count} reviews of ${product}"""
+            }
+          }
+
+        }
+      }
+    }
+
+    // price
+    def price(price: Money)(using locale: Locale): String = {
+      locale match {
+        case Locale.Da => {
+          s"""Pris: ${Format.money(price)}"""
+        }
+        case Locale.En => {
+          s"""Price: ${Format.money(price)}"""
+        }
+      }
+    }
+  }
+}
+
+// format: on
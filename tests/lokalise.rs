@@ -0,0 +1,283 @@
+//! Runs the binary against a mock Lokalise API serving the responses in
+//! `tests/fixtures/lokalise`.
+
+mod support;
+
+use std::fs;
+use support::{fixture_path, run, run_in, test_dir, MockLokalise, Response, API_TOKEN};
+
+const PROJECTS: &str = "projects?page=1&limit=100";
+const UNDO_KEYS: &str = "projects/1111.aaaa/keys?include_translations=1&limit=2&pagination=cursor";
+const UNDO_KEYS_PAGE_2: &str =
+    "projects/1111.aaaa/keys?include_translations=1&limit=2&pagination=cursor&cursor=next-page";
+const CAR_KEYS: &str = "projects/2222.bbbb/keys?include_translations=1&limit=2&pagination=cursor";
+
+fn config(server: &MockLokalise, extra: &str) -> String {
+    format!(
+        r#"projects = ["Undo", "Car"]
+
+[lokalise]
+base_url = "{}"
+page_size = 2
+{}
+
+[lokalise.retry]
+initial_backoff_ms = 1
+"#,
+        server.base_url(),
+        extra
+    )
+}
+
+/// Serve both projects, with the keys of `Undo` split over two pages.
+///
+/// Like Lokalise, the total number of keys isn't reported with cursor pagination.
+fn serve_projects(server: &MockLokalise) {
+    server
+        .on(PROJECTS, Response::fixture("projects.json"))
+        .on(
+            UNDO_KEYS,
+            Response::fixture("undo_keys_1.json").header("X-Pagination-Next-Cursor", "next-page"),
+        )
+        .on(UNDO_KEYS_PAGE_2, Response::fixture("undo_keys_2.json"))
+        .on(CAR_KEYS, Response::fixture("car_keys.json"));
+}
+
+fn stdout(output: &std::process::Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &std::process::Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+#[test]
+fn generates_scala_from_all_pages() {
+    let server = MockLokalise::start();
+    serve_projects(&server);
+
+    let output = run(
+        "generates_scala_from_all_pages",
+        &config(&server, ""),
+        &["--stdout"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        fs::read_to_string(fixture_path("I18n.scala")).unwrap()
+    );
+    assert!(server
+        .requests()
+        .iter()
+        .all(|request| request.api_token.as_deref() == Some(API_TOKEN)));
+}
+
+#[test]
+fn pages_through_keys_by_number() {
+    let server = MockLokalise::start();
+    server
+        .on(PROJECTS, Response::fixture("projects.json"))
+        .on(
+            "projects/1111.aaaa/keys?include_translations=1&limit=2&page=1",
+            Response::fixture("undo_keys_1.json")
+                .header("X-Pagination-Total-Count", "3")
+                .header("X-Pagination-Page-Count", "2"),
+        )
+        .on(
+            "projects/1111.aaaa/keys?include_translations=1&limit=2&page=2",
+            Response::fixture("undo_keys_2.json")
+                .header("X-Pagination-Total-Count", "3")
+                .header("X-Pagination-Page-Count", "2"),
+        )
+        .on(
+            "projects/2222.bbbb/keys?include_translations=1&limit=2&page=1",
+            Response::fixture("car_keys.json")
+                .header("X-Pagination-Total-Count", "1")
+                .header("X-Pagination-Page-Count", "1"),
+        );
+
+    let output = run(
+        "pages_through_keys_by_number",
+        &config(&server, r#"pagination = "offset""#),
+        &["--stdout"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        fs::read_to_string(fixture_path("I18n.scala")).unwrap()
+    );
+}
+
+#[test]
+fn fails_if_fewer_keys_than_reported_are_fetched() {
+    let server = MockLokalise::start();
+    server.on(PROJECTS, Response::fixture("projects.json")).on(
+        "projects/1111.aaaa/keys?include_translations=1&limit=2&page=1",
+        Response::fixture("undo_keys_1.json")
+            .header("X-Pagination-Total-Count", "3")
+            .header("X-Pagination-Page-Count", "1"),
+    );
+
+    let output = run(
+        "fails_if_fewer_keys_than_reported_are_fetched",
+        &config(&server, r#"pagination = "offset""#),
+        &["--stdout"],
+    );
+
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("Fetched 2 keys from the Undo project but Lokalise reported 3")
+    );
+}
+
+#[test]
+fn retries_rate_limited_requests() {
+    let server = MockLokalise::start();
+    server.on(
+        PROJECTS,
+        Response::json(
+            429,
+            r#"{"error":{"message":"Too many requests","code":429}}"#,
+        )
+        .header("Retry-After", "0"),
+    );
+    server.on(PROJECTS, Response::json(500, ""));
+    serve_projects(&server);
+
+    let output = run(
+        "retries_rate_limited_requests",
+        &config(&server, ""),
+        &["--stdout"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let project_requests = server
+        .requests()
+        .iter()
+        .filter(|request| request.path == format!("/api2/{}", PROJECTS))
+        .count();
+    assert_eq!(project_requests, 3);
+}
+
+#[test]
+fn reports_invalid_api_token() {
+    let server = MockLokalise::start();
+    server.on(
+        PROJECTS,
+        Response::json(
+            401,
+            r#"{"error":{"message":"Invalid `X-Api-Token` header","code":401}}"#,
+        ),
+    );
+
+    let output = run(
+        "reports_invalid_api_token",
+        &config(&server, ""),
+        &["--stdout"],
+    );
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Lokalise rejected the API token"));
+    assert!(stderr(&output).contains("Invalid `X-Api-Token` header (code 401)"));
+}
+
+#[test]
+fn redacts_api_token_from_decode_errors() {
+    let server = MockLokalise::start();
+    server.on(
+        PROJECTS,
+        Response::json(200, &format!(r#"{{"unexpected": "{}"}}"#, API_TOKEN)),
+    );
+
+    let output = run(
+        "redacts_api_token_from_decode_errors",
+        &config(&server, ""),
+        &["--stdout"],
+    );
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Failed to decode response from Lokalise"));
+    assert!(stderr(&output).contains("[REDACTED]"));
+    assert!(!stderr(&output).contains(API_TOKEN));
+}
+
+#[test]
+fn suggests_similar_project_names() {
+    let server = MockLokalise::start();
+    serve_projects(&server);
+
+    let output = run(
+        "suggests_similar_project_names",
+        &config(&server, "").replace(r#"["Undo", "Car"]"#, r#"["undo web"]"#),
+        &["--stdout"],
+    );
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains(
+        r#"Couldn't find a Lokalise project with the name or ID "undo web". Did you mean "Undo Web""#
+    ));
+}
+
+#[test]
+fn finds_projects_by_id() {
+    let server = MockLokalise::start();
+    serve_projects(&server);
+
+    let output = run(
+        "finds_projects_by_id",
+        &config(&server, "").replace(r#"["Undo", "Car"]"#, r#"["1111.aaaa", "Car"]"#),
+        &["--stdout"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        fs::read_to_string(fixture_path("I18n.scala")).unwrap()
+    );
+}
+
+#[test]
+fn deletes_files_of_removed_projects_in_split_mode() {
+    let server = MockLokalise::start();
+    serve_projects(&server);
+    serve_projects(&server);
+
+    let dir = test_dir("deletes_files_of_removed_projects_in_split_mode");
+    fs::create_dir_all(dir.join("i18n")).unwrap();
+    let removed = dir.join("i18n/Removed.scala");
+    let handwritten = dir.join("i18n/Handwritten.scala");
+    fs::write(&removed, "// Generated by i18n-code-gen. Don't edit.\n").unwrap();
+    fs::write(&handwritten, "// format: off\n").unwrap();
+    let config = config(&server, "\n[split]\ndir = \"i18n\"");
+
+    let output = run_in(&dir, &config, &["--check"]);
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("-// Generated by i18n-code-gen. Don't edit."));
+    assert!(stderr(&output).contains("Removed.scala"));
+    assert!(!stderr(&output).contains("Handwritten.scala"));
+
+    let output = run_in(&dir, &config, &[]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(!removed.exists());
+    assert!(handwritten.exists());
+    assert!(dir.join("i18n/undo.scala").exists());
+}
+
+#[test]
+fn rejects_output_and_object_in_split_mode() {
+    let server = MockLokalise::start();
+    let config = format!(
+        "output = \"I18n.scala\"\nobject = \"I18n\"\n{}",
+        config(&server, "\n[split]")
+    );
+
+    let output = run("rejects_output_and_object_in_split_mode", &config, &[]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("`output` and `object` in"));
+    assert!(stderr(&output).contains("can't be used with `[split]`"));
+}
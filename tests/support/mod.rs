@@ -0,0 +1,201 @@
+//! A mock Lokalise API serving recorded JSON responses, and helpers for running the binary
+//! against it.
+
+#![allow(dead_code)]
+
+use std::{
+    collections::VecDeque,
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+pub const API_TOKEN: &str = "secret-test-token";
+
+/// A canned response to a request.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    /// A `200 OK` with the contents of a file in `tests/fixtures/lokalise`.
+    pub fn fixture(name: &str) -> Self {
+        Self::json(200, &fs::read_to_string(fixture_path(name)).unwrap())
+    }
+
+    pub fn json(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request the server received.
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Path including the query string.
+    pub path: String,
+    pub api_token: Option<String>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Responses by path including the query string. Each is used once, in order.
+    routes: Vec<(String, VecDeque<Response>)>,
+    requests: Vec<Request>,
+}
+
+/// An HTTP server standing in for the Lokalise API.
+///
+/// Requests for paths without a response left get a 404 in Lokalise's error format.
+pub struct MockLokalise {
+    addr: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockLokalise {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let server_state = Arc::clone(&state);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => handle(stream, &server_state),
+                    Err(_) => return,
+                }
+            }
+        });
+
+        Self { addr, state }
+    }
+
+    /// Respond to the next request for `path` with `response`.
+    ///
+    /// `path` is relative to the API root and includes the query string, like
+    /// `projects?page=1&limit=100`.
+    pub fn on(&self, path: &str, response: Response) -> &Self {
+        let path = format!("/api2/{}", path);
+        let mut state = self.state.lock().unwrap();
+        match state.routes.iter_mut().find(|(p, _)| *p == path) {
+            Some((_, responses)) => responses.push_back(response),
+            None => state.routes.push((path, vec![response].into())),
+        }
+        self
+    }
+
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api2", self.addr)
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>) {
+    let mut reader = BufReader::new(&stream);
+
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+    let path = request_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap_or("")
+        .to_string();
+
+    let mut api_token = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).is_err() || line.trim().is_empty() {
+            break;
+        }
+        let mut parts = line.splitn(2, ':');
+        let name = parts.next().unwrap_or("").trim().to_lowercase();
+        if name == "x-api-token" {
+            api_token = parts.next().map(|value| value.trim().to_string());
+        }
+    }
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(Request {
+            path: path.clone(),
+            api_token,
+        });
+        state
+            .routes
+            .iter_mut()
+            .find(|(p, _)| *p == path)
+            .and_then(|(_, responses)| responses.pop_front())
+            .unwrap_or_else(|| {
+                Response::json(404, r#"{"error":{"message":"Not Found","code":404}}"#)
+            })
+    };
+
+    let mut out = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        out.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    out.push_str("\r\n");
+    out.push_str(&response.body);
+
+    (&stream).write_all(out.as_bytes()).ok();
+}
+
+pub fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/lokalise")
+        .join(name)
+}
+
+/// Run the binary with `args` and a config file in a fresh directory named after the test.
+pub fn run(test_name: &str, config: &str, args: &[&str]) -> Output {
+    run_in(&test_dir(test_name), config, args)
+}
+
+/// A fresh, empty directory named after the test.
+pub fn test_dir(test_name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(test_name);
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Run the binary with `args` and a config file in `dir`, keeping what's already there.
+pub fn run_in(dir: &Path, config: &str, args: &[&str]) -> Output {
+    let config_path = dir.join("i18n-code-gen.toml");
+    fs::write(&config_path, config).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_i18n-code-gen"))
+        .args(args)
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--token")
+        .arg(API_TOKEN)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+}